@@ -0,0 +1,560 @@
+// Timing harness comparing DynamicLinkedList, StaticLinkedList and UnrolledList with Vec,
+// VecDeque and std's LinkedList. Uses only std, so it runs offline:
+//
+//   cargo bench --bench lists                        markdown table on stdout
+//   cargo bench --bench lists -- --format csv        CSV on stdout
+//   cargo bench --bench lists -- --output out.csv    also write CSV to a file
+//   cargo bench --bench lists -- --quick             skip the largest size
+//   cargo bench --bench lists -- search              only operations whose name contains "search"
+//
+// Every figure is the median over several runs of the time per operation in nanoseconds.
+// Setup (filling the list beforehand) is not timed.
+
+use std::collections::{LinkedList, VecDeque};
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use payton_Assignment_2::dynamic_list::DynamicLinkedList;
+use payton_Assignment_2::static_list::StaticLinkedList;
+use payton_Assignment_2::unrolled_list::UnrolledList;
+
+const RUNS: usize = 7;
+// The most lookups a get or search measurement makes, so they stay quick on long lists.
+const MAX_LOOKUPS: usize = 1_000;
+// Elements per UnrolledList node.
+const UNROLLED_NODE: usize = 32;
+
+// An element type the harness can create from a counter and reduce to a number.
+trait Element: Clone + PartialEq {
+    const NAME: &'static str;
+    fn make(i: usize) -> Self;
+    fn key(&self) -> u64;
+}
+
+impl Element for u64 {
+    const NAME: &'static str = "u64";
+
+    fn make(i: usize) -> Self {
+        i as u64
+    }
+
+    fn key(&self) -> u64 {
+        *self
+    }
+}
+
+// A 128 byte element, to see how moving larger values changes the picture.
+impl Element for [u64; 16] {
+    const NAME: &'static str = "[u64; 16]";
+
+    fn make(i: usize) -> Self {
+        [i as u64; 16]
+    }
+
+    fn key(&self) -> u64 {
+        self[0]
+    }
+}
+
+// The operations every implementation provides, written with its natural API.
+trait Subject<T: Element> {
+    const NAME: &'static str;
+    fn empty() -> Self;
+    fn push_back(&mut self, data: T);
+    fn push_front(&mut self, data: T);
+    fn insert_at(&mut self, index: usize, data: T);
+    fn get(&self, index: usize) -> Option<T>;
+    fn contains(&self, data: &T) -> bool;
+    fn remove_at(&mut self, index: usize);
+    fn sum(&self) -> u64;
+}
+
+impl<T: Element> Subject<T> for DynamicLinkedList<T> {
+    const NAME: &'static str = "DynamicLinkedList";
+
+    fn empty() -> Self {
+        DynamicLinkedList::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.insert(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        self.insert_at_index(0, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        self.insert_at_index(index, data);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        DynamicLinkedList::get(self, index)
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        self.find(data.clone())
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        self.delete_at_index(index);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+impl<T: Element, const N: usize> Subject<T> for StaticLinkedList<T, N> {
+    const NAME: &'static str = "StaticLinkedList";
+
+    fn empty() -> Self {
+        StaticLinkedList::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.insert(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        self.insert_at_index(0, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        self.insert_at_index(index, data);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        StaticLinkedList::get(self, index)
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        self.find(data.clone())
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        self.delete_at_index(index);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+impl<T: Element, const B: usize> Subject<T> for UnrolledList<T, B> {
+    const NAME: &'static str = "UnrolledList";
+
+    fn empty() -> Self {
+        UnrolledList::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.insert(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        UnrolledList::push_front(self, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        self.insert_at_index(index, data);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        UnrolledList::get(self, index)
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        self.find(data.clone())
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        self.delete_at_index(index);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+impl<T: Element> Subject<T> for Vec<T> {
+    const NAME: &'static str = "Vec";
+
+    fn empty() -> Self {
+        Vec::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        self.push(data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        self.insert(0, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        self.insert(index, data);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        self.as_slice().get(index).cloned()
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        self.as_slice().contains(data)
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        self.remove(index);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+impl<T: Element> Subject<T> for VecDeque<T> {
+    const NAME: &'static str = "VecDeque";
+
+    fn empty() -> Self {
+        VecDeque::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        VecDeque::push_back(self, data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        VecDeque::push_front(self, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        self.insert(index, data);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        VecDeque::get(self, index).cloned()
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        VecDeque::contains(self, data)
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        self.remove(index);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+// std's LinkedList has no positional insert or remove on stable, so those
+// split the list, work on the join and append the rest back.
+impl<T: Element> Subject<T> for LinkedList<T> {
+    const NAME: &'static str = "LinkedList";
+
+    fn empty() -> Self {
+        LinkedList::new()
+    }
+
+    fn push_back(&mut self, data: T) {
+        LinkedList::push_back(self, data);
+    }
+
+    fn push_front(&mut self, data: T) {
+        LinkedList::push_front(self, data);
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) {
+        let mut rest = self.split_off(index);
+        LinkedList::push_back(self, data);
+        self.append(&mut rest);
+    }
+
+    fn get(&self, index: usize) -> Option<T> {
+        self.iter().nth(index).cloned()
+    }
+
+    fn contains(&self, data: &T) -> bool {
+        LinkedList::contains(self, data)
+    }
+
+    fn remove_at(&mut self, index: usize) {
+        let mut rest = self.split_off(index);
+        rest.pop_front();
+        self.append(&mut rest);
+    }
+
+    fn sum(&self) -> u64 {
+        self.iter().map(Element::key).sum()
+    }
+}
+
+// One line of results.
+struct Row {
+    operation: &'static str,
+    element: &'static str,
+    size: usize,
+    implementation: &'static str,
+    ns_per_op: f64,
+}
+
+// Times op on a fresh value from setup, RUNS times, and returns the median
+// nanoseconds per operation given that one call of op does ops operations.
+fn measure<S>(ops: usize, setup: impl Fn() -> S, mut op: impl FnMut(&mut S)) -> f64 {
+    let mut times: Vec<Duration> = (0..RUNS)
+        .map(|_| {
+            let mut subject = setup();
+            let start = Instant::now();
+            op(&mut subject);
+            let elapsed = start.elapsed();
+            black_box(&subject);
+            elapsed
+        })
+        .collect();
+    times.sort();
+    times[RUNS / 2].as_nanos() as f64 / ops.max(1) as f64
+}
+
+fn filled<T: Element, S: Subject<T>>(size: usize) -> S {
+    let mut subject = S::empty();
+    for i in 0..size {
+        subject.push_back(T::make(i));
+    }
+    subject
+}
+
+// The positions a get or search measurement visits, spread evenly over the list.
+fn lookups(size: usize) -> Vec<usize> {
+    let count = size.min(MAX_LOOKUPS);
+    (0..count).map(|i| i * size / count).collect()
+}
+
+// Measures every operation for one implementation, element type and size.
+fn bench<T: Element, S: Subject<T>>(size: usize, filter: Option<&str>, rows: &mut Vec<Row>) {
+    let mut record = |operation: &'static str, run: &dyn Fn() -> f64| {
+        if filter.is_some_and(|filter| !operation.contains(filter)) {
+            return;
+        }
+        rows.push(Row {
+            operation,
+            element: T::NAME,
+            size,
+            implementation: S::NAME,
+            ns_per_op: run(),
+        });
+    };
+
+    record("append", &|| {
+        measure(size, S::empty, |s| {
+            for i in 0..size {
+                s.push_back(T::make(i));
+            }
+        })
+    });
+    record("front insert", &|| {
+        measure(size, S::empty, |s| {
+            for i in 0..size {
+                s.push_front(T::make(i));
+            }
+        })
+    });
+    record("middle insert", &|| {
+        measure(size, S::empty, |s| {
+            for i in 0..size {
+                s.insert_at(i / 2, T::make(i));
+            }
+        })
+    });
+    record("indexed get", &|| {
+        let indices = lookups(size);
+        measure(
+            indices.len(),
+            || filled::<T, S>(size),
+            |s| {
+                for &i in &indices {
+                    black_box(s.get(i));
+                }
+            },
+        )
+    });
+    record("search", &|| {
+        let targets: Vec<T> = lookups(size).into_iter().map(T::make).collect();
+        measure(
+            targets.len(),
+            || filled::<T, S>(size),
+            |s| {
+                for target in &targets {
+                    black_box(s.contains(target));
+                }
+            },
+        )
+    });
+    record("delete", &|| {
+        measure(
+            size,
+            || filled::<T, S>(size),
+            |s| {
+                for remaining in (1..=size).rev() {
+                    s.remove_at(remaining / 2);
+                }
+            },
+        )
+    });
+    record("iterate", &|| {
+        measure(
+            size,
+            || filled::<T, S>(size),
+            |s| {
+                black_box(s.sum());
+            },
+        )
+    });
+}
+
+// Runs every implementation at one size. StaticLinkedList needs its capacity
+// as a constant, so each size is listed here with a matching literal.
+macro_rules! bench_sizes {
+    ($rows:expr, $filter:expr, $quick:expr, $element:ty, [$($size:literal),*], $largest:literal) => {
+        $(
+            if !($quick && $size == $largest) {
+                bench::<$element, DynamicLinkedList<$element>>($size, $filter, $rows);
+                bench::<$element, StaticLinkedList<$element, $size>>($size, $filter, $rows);
+                bench::<$element, UnrolledList<$element, UNROLLED_NODE>>($size, $filter, $rows);
+                bench::<$element, Vec<$element>>($size, $filter, $rows);
+                bench::<$element, VecDeque<$element>>($size, $filter, $rows);
+                bench::<$element, LinkedList<$element>>($size, $filter, $rows);
+            }
+        )*
+    };
+}
+
+const IMPLEMENTATIONS: [&str; 6] = [
+    "DynamicLinkedList",
+    "StaticLinkedList",
+    "UnrolledList",
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+];
+
+fn to_csv(rows: &[Row]) -> String {
+    let mut csv = String::from("operation,element,size,implementation,ns_per_op\n");
+    for row in rows {
+        let _ = writeln!(
+            csv,
+            "{},\"{}\",{},{},{:.2}",
+            row.operation, row.element, row.size, row.implementation, row.ns_per_op
+        );
+    }
+    csv
+}
+
+// One line per operation, element type and size, with a column per implementation.
+fn to_markdown(rows: &[Row]) -> String {
+    let mut table = String::from("| operation | element | size |");
+    for implementation in IMPLEMENTATIONS {
+        let _ = write!(table, " {implementation} (ns/op) |");
+    }
+    table.push_str("\n|---|---|---:|");
+    table.push_str(&"---:|".repeat(IMPLEMENTATIONS.len()));
+    table.push('\n');
+
+    let mut keys: Vec<(&str, &str, usize)> = Vec::new();
+    for row in rows {
+        let key = (row.operation, row.element, row.size);
+        if !keys.contains(&key) {
+            keys.push(key);
+        }
+    }
+    for (operation, element, size) in keys {
+        let _ = write!(table, "| {operation} | {element} | {size} |");
+        for implementation in IMPLEMENTATIONS {
+            let cell = rows.iter().find(|row| {
+                (row.operation, row.element, row.size, row.implementation)
+                    == (operation, element, size, implementation)
+            });
+            match cell {
+                Some(row) => {
+                    let _ = write!(table, " {:.1} |", row.ns_per_op);
+                }
+                None => table.push_str(" - |"),
+            }
+        }
+        table.push('\n');
+    }
+    table
+}
+
+struct Options {
+    csv: bool,
+    output: Option<String>,
+    quick: bool,
+    filter: Option<String>,
+}
+
+fn parse_args() -> Options {
+    let mut options = Options {
+        csv: false,
+        output: None,
+        quick: false,
+        filter: None,
+    };
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // Passed by cargo bench to every bench target.
+            "--bench" => {}
+            "--quick" => options.quick = true,
+            "--format" => match args.next().as_deref() {
+                Some("csv") => options.csv = true,
+                Some("markdown") => options.csv = false,
+                other => panic!("--format takes csv or markdown, got {other:?}"),
+            },
+            "--output" => options.output = Some(args.next().expect("--output takes a path")),
+            _ if arg.starts_with("--") => panic!("unknown option {arg}"),
+            _ => options.filter = Some(arg),
+        }
+    }
+    options
+}
+
+fn main() {
+    let options = parse_args();
+
+    // A 10 000 slot StaticLinkedList of 128 byte elements is built on the stack,
+    // so run on a thread with room for it.
+    let filter = options.filter.clone();
+    let quick = options.quick;
+    let rows = std::thread::Builder::new()
+        .stack_size(256 * 1024 * 1024)
+        .spawn(move || {
+            let mut rows = Vec::new();
+            let filter = filter.as_deref();
+            bench_sizes!(&mut rows, filter, quick, u64, [100, 1000, 10000], 10000);
+            bench_sizes!(
+                &mut rows,
+                filter,
+                quick,
+                [u64; 16],
+                [100, 1000, 10000],
+                10000
+            );
+            rows
+        })
+        .expect("failed to start the benchmark thread")
+        .join()
+        .expect("benchmark thread panicked");
+
+    if options.csv {
+        print!("{}", to_csv(&rows));
+    } else {
+        print!("{}", to_markdown(&rows));
+    }
+    if let Some(path) = options.output {
+        std::fs::write(&path, to_csv(&rows)).expect("failed to write the CSV output");
+        eprintln!("wrote {path}");
+    }
+}
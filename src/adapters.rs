@@ -0,0 +1,259 @@
+use std::collections::LinkedList;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::static_list::StaticLinkedList;
+use crate::storage::{DequeStorage, ListStorage};
+
+// Error returned when push is called on an adapter whose storage has no space available.
+// The element is handed back to the caller.
+#[derive(Debug, PartialEq)]
+pub struct Full<T>(pub T);
+
+impl<T> fmt::Display for Full<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the list is full")
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for Full<T> {}
+
+// A first in, first out queue over a list: elements are pushed at the tail and popped
+// from the head, both O(1) on every ListStorage.
+// T: The type of data stored in the queue.
+// L: The storage the elements live in, a DynamicLinkedList by default.
+pub struct ListQueue<T, L: ListStorage<T> = DynamicLinkedList<T>> {
+    list: L,
+    marker: PhantomData<T>,
+}
+
+// A queue with room for N elements, stored in a StaticLinkedList.
+pub type BoundedQueue<T, const N: usize> = ListQueue<T, StaticLinkedList<T, N>>;
+
+impl<T, L: ListStorage<T>> ListQueue<T, L> {
+    pub fn new() -> Self {
+        ListQueue {
+            list: L::default(),
+            marker: PhantomData,
+        }
+    }
+
+    // Returns the number of elements in the queue.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // Returns true if the queue has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Adds data at the back of the queue.
+    // Returns Full with the data if there is no space available.
+    // data: T: The element to add.
+    pub fn push(&mut self, data: T) -> Result<(), Full<T>> {
+        self.list.push_back(data).map_err(Full)
+    }
+
+    // Removes and returns the element at the front of the queue, or None if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    // Returns a reference to the element that pop would return next, or None if the queue is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    // Returns an iterator over the elements, from the front of the queue to the back.
+    pub fn iter(&self) -> L::Iter<'_> {
+        self.list.iter()
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    // Returns the underlying list, front of the queue first.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T, L: ListStorage<T>> Default for ListQueue<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, L: ListStorage<T>> fmt::Debug for ListQueue<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+// A last in, first out stack over a list: elements are pushed and popped at the head,
+// both O(1) on every ListStorage.
+// T: The type of data stored in the stack.
+// L: The storage the elements live in, a DynamicLinkedList by default.
+pub struct ListStack<T, L: ListStorage<T> = DynamicLinkedList<T>> {
+    list: L,
+    marker: PhantomData<T>,
+}
+
+// A stack with room for N elements, stored in a StaticLinkedList.
+pub type BoundedStack<T, const N: usize> = ListStack<T, StaticLinkedList<T, N>>;
+
+impl<T, L: ListStorage<T>> ListStack<T, L> {
+    pub fn new() -> Self {
+        ListStack {
+            list: L::default(),
+            marker: PhantomData,
+        }
+    }
+
+    // Returns the number of elements on the stack.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // Returns true if the stack has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Puts data on top of the stack.
+    // Returns Full with the data if there is no space available.
+    // data: T: The element to add.
+    pub fn push(&mut self, data: T) -> Result<(), Full<T>> {
+        self.list.push_front(data).map_err(Full)
+    }
+
+    // Removes and returns the element on top of the stack, or None if it is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    // Returns a reference to the element on top of the stack, or None if it is empty.
+    pub fn peek(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    // Returns an iterator over the elements, from the top of the stack to the bottom.
+    pub fn iter(&self) -> L::Iter<'_> {
+        self.list.iter()
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    // Returns the underlying list, top of the stack first.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T, L: ListStorage<T>> Default for ListStack<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, L: ListStorage<T>> fmt::Debug for ListStack<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
+
+// A double-ended queue over a doubly linked list: elements are pushed and popped at either end,
+// all O(1) on every DequeStorage.
+// T: The type of data stored in the deque.
+// L: The storage the elements live in, the standard library's LinkedList by default.
+pub struct ListDeque<T, L: DequeStorage<T> = LinkedList<T>> {
+    list: L,
+    marker: PhantomData<T>,
+}
+
+impl<T, L: DequeStorage<T>> ListDeque<T, L> {
+    pub fn new() -> Self {
+        ListDeque {
+            list: L::default(),
+            marker: PhantomData,
+        }
+    }
+
+    // Returns the number of elements in the deque.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // Returns true if the deque has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Adds data at the front of the deque.
+    // Returns Full with the data if there is no space available.
+    // data: T: The element to add.
+    pub fn push_front(&mut self, data: T) -> Result<(), Full<T>> {
+        self.list.push_front(data).map_err(Full)
+    }
+
+    // Adds data at the back of the deque.
+    // Returns Full with the data if there is no space available.
+    // data: T: The element to add.
+    pub fn push_back(&mut self, data: T) -> Result<(), Full<T>> {
+        self.list.push_back(data).map_err(Full)
+    }
+
+    // Removes and returns the element at the front, or None if the deque is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        self.list.pop_front()
+    }
+
+    // Removes and returns the element at the back, or None if the deque is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        self.list.pop_back()
+    }
+
+    // Returns a reference to the element at the front, or None if the deque is empty.
+    pub fn peek_front(&self) -> Option<&T> {
+        self.list.front()
+    }
+
+    // Returns a reference to the element at the back, or None if the deque is empty.
+    pub fn peek_back(&self) -> Option<&T> {
+        self.list.back()
+    }
+
+    // Returns an iterator over the elements, from front to back.
+    pub fn iter(&self) -> L::Iter<'_> {
+        self.list.iter()
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        self.list.clear();
+    }
+
+    // Returns the underlying list, front first.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+}
+
+impl<T, L: DequeStorage<T>> Default for ListDeque<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, L: DequeStorage<T>> fmt::Debug for ListDeque<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
@@ -0,0 +1,201 @@
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+
+// Marks the end of the free list in a packed head or a slot's next field.
+const NIL: u32 = u32::MAX;
+
+// A fixed-capacity object pool that many threads can allocate from at once.
+// Like the free chain of StaticLinkedList, unused slots are chained by index from free, but
+// the chain is a lock-free Treiber stack: a slot is taken or given back with a single
+// compare-and-swap on the head. The head packs the top slot index with a tag that changes on
+// every update, so a thread whose view of the head is stale cannot succeed just because the
+// same index came back to the top in the meantime (the ABA problem).
+// T: The type of data stored in the pool.
+// N: The number of slots, which must be less than u32::MAX.
+pub struct AtomicSlotPool<T, const N: usize> {
+    slots: [PoolNode<T>; N],
+    // The tag in the high 32 bits and the index of the first free slot in the low 32 bits.
+    free: AtomicU64,
+    len: AtomicUsize,
+}
+
+struct PoolNode<T> {
+    // Initialised exactly while the slot is handed out.
+    data: UnsafeCell<MaybeUninit<T>>,
+    // The next free slot, only meaningful while this one is on the free list.
+    next: AtomicU64,
+}
+
+// A slot handed out by the pool. It dereferences to the data and gives the slot back
+// to the pool when it is dropped.
+pub struct PoolSlot<'a, T, const N: usize> {
+    pool: &'a AtomicSlotPool<T, N>,
+    index: usize,
+}
+
+// A slot's data is only reached through the one PoolSlot that owns it, which may be
+// shared between threads, and the free list itself is made of atomics.
+unsafe impl<T: Send + Sync, const N: usize> Sync for AtomicSlotPool<T, N> {}
+
+fn pack(tag: u32, index: u32) -> u64 {
+    (tag as u64) << 32 | index as u64
+}
+
+fn unpack(head: u64) -> (u32, u32) {
+    ((head >> 32) as u32, head as u32)
+}
+
+impl<T, const N: usize> AtomicSlotPool<T, N> {
+    pub fn new() -> Self {
+        const {
+            assert!(
+                N < NIL as usize,
+                "AtomicSlotPool capacity must fit in a u32"
+            )
+        };
+        // Every slot starts out on the free list, chained in index order.
+        let slots = std::array::from_fn(|i| {
+            let next = if i + 1 < N { i as u32 + 1 } else { NIL }; // End of free list
+            PoolNode {
+                data: UnsafeCell::new(MaybeUninit::uninit()),
+                next: AtomicU64::new(next as u64),
+            }
+        });
+
+        AtomicSlotPool {
+            slots,
+            free: AtomicU64::new(pack(0, if N > 0 { 0 } else { NIL })),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    // Returns the maximum number of slots that can be handed out at once.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    // Returns the number of slots handed out. Other threads may change it straight away.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    // Returns true if no slots are handed out, with the same caveat as len.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Takes a slot off the free list and stores data in it.
+    // Returns the data back if every slot is in use.
+    // data: T: The element to store.
+    pub fn acquire(&self, data: T) -> Result<PoolSlot<'_, T, N>, T> {
+        let mut head = self.free.load(Ordering::Acquire);
+        let index = loop {
+            let (tag, index) = unpack(head);
+            if index == NIL {
+                return Err(data);
+            }
+            // The slot may be taken by another thread before the exchange below, in which case
+            // next is stale, but then the head's tag has changed and the exchange fails.
+            let next = self.slots[index as usize].next.load(Ordering::Relaxed);
+            match self.free.compare_exchange_weak(
+                head,
+                pack(tag.wrapping_add(1), next as u32),
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => break index as usize,
+                Err(actual) => head = actual,
+            }
+        };
+
+        // The slot is off the free list, so no other thread touches its data.
+        unsafe { (*self.slots[index].data.get()).write(data) };
+        self.len.fetch_add(1, Ordering::Relaxed);
+        Ok(PoolSlot { pool: self, index })
+    }
+
+    // Pushes a slot whose data has already been moved out back onto the free list.
+    fn release(&self, index: usize) {
+        self.len.fetch_sub(1, Ordering::Relaxed);
+        let mut head = self.free.load(Ordering::Relaxed);
+        loop {
+            let (tag, top) = unpack(head);
+            self.slots[index].next.store(top as u64, Ordering::Relaxed);
+            // Release publishes the next link and the data moved out of the slot
+            // to the thread that takes it next.
+            match self.free.compare_exchange_weak(
+                head,
+                pack(tag.wrapping_add(1), index as u32),
+                Ordering::Release,
+                Ordering::Relaxed,
+            ) {
+                Ok(_) => return,
+                Err(actual) => head = actual,
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> PoolSlot<'_, T, N> {
+    // Returns the index of the slot in the pool. No two live slots share an index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    // Moves the data out and gives the slot back to the pool.
+    pub fn into_inner(self) -> T {
+        let (pool, index) = (self.pool, self.index);
+        mem::forget(self);
+        let data = unsafe { (*pool.slots[index].data.get()).assume_init_read() };
+        pool.release(index);
+        data
+    }
+}
+
+impl<T, const N: usize> Deref for PoolSlot<'_, T, N> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        unsafe { (*self.pool.slots[self.index].data.get()).assume_init_ref() }
+    }
+}
+
+impl<T, const N: usize> DerefMut for PoolSlot<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut T {
+        unsafe { (*self.pool.slots[self.index].data.get()).assume_init_mut() }
+    }
+}
+
+impl<T, const N: usize> Drop for PoolSlot<'_, T, N> {
+    fn drop(&mut self) {
+        unsafe { (*self.pool.slots[self.index].data.get()).assume_init_drop() };
+        self.pool.release(self.index);
+    }
+}
+
+impl<T, const N: usize> Default for AtomicSlotPool<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for AtomicSlotPool<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AtomicSlotPool")
+            .field("capacity", &N)
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for PoolSlot<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PoolSlot")
+            .field("index", &self.index)
+            .field("data", &**self)
+            .finish()
+    }
+}
@@ -0,0 +1,265 @@
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::static_list::StaticNode;
+use crate::storage::SlotStorage;
+
+// A singly linked list whose tail links back to its head, with a movable current position,
+// for round-robin work such as handing tasks to workers in turn.
+// Nodes are StaticNodes linked by slot index. The list keeps the slot before the current one,
+// so inserting on either side of the current element and removing it are all O(1).
+// T: The type of data stored in the list.
+// S: The slots the nodes live in, a growable Vec by default.
+pub struct CircularList<T, S: SlotStorage<T> = Vec<StaticNode<T>>> {
+    slots: S,
+    free: Option<usize>,
+    // The slot before the current one, or None if the list is empty.
+    // In a one element list it is the current slot itself.
+    prev: Option<usize>,
+    len: usize,
+    marker: PhantomData<T>,
+}
+
+// A CircularList with room for N elements in a fixed size array.
+pub type StaticCircularList<T, const N: usize> = CircularList<T, [StaticNode<T>; N]>;
+
+impl<T, S: SlotStorage<T>> CircularList<T, S> {
+    pub fn new() -> Self {
+        let slots = S::vacant();
+        let free = if slots.slots().is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        CircularList {
+            slots,
+            free,
+            prev: None,
+            len: 0,
+            marker: PhantomData,
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns a reference to the current element, or None if the list is empty.
+    pub fn current(&self) -> Option<&T> {
+        self.current_slot().map(|index| self.data(index))
+    }
+
+    // Returns a mutable reference to the current element, or None if the list is empty.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        let index = self.current_slot()?;
+        self.slots.slots_mut()[index].data.as_mut()
+    }
+
+    // Moves the current position one element forward, wrapping from the tail to the head.
+    // Does nothing if the list is empty.
+    pub fn advance(&mut self) {
+        self.prev = self.current_slot();
+    }
+
+    // Moves the current position n elements forward. n is taken modulo the length.
+    // n: usize: The number of elements to move by.
+    pub fn advance_by(&mut self, n: usize) {
+        if self.len > 0 {
+            for _ in 0..n % self.len {
+                self.advance();
+            }
+        }
+    }
+
+    // Inserts data just after the current element, so it becomes current after one advance.
+    // In an empty list the new element becomes the current one.
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn insert_after_current(&mut self, data: T) -> Result<(), T> {
+        let index = self.alloc(data)?;
+        match self.current_slot() {
+            Some(current) => {
+                self.link_after(current, index);
+                // With one element the current slot was also prev; the new slot now precedes it.
+                if self.prev == Some(current) {
+                    self.prev = Some(index);
+                }
+            }
+            None => self.link_alone(index),
+        }
+        Ok(())
+    }
+
+    // Inserts data just before the current element, so it is reached last by a full cycle
+    // of advances. In an empty list the new element becomes the current one.
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn insert_before_current(&mut self, data: T) -> Result<(), T> {
+        let index = self.alloc(data)?;
+        match self.prev {
+            Some(prev) => {
+                self.link_after(prev, index);
+                self.prev = Some(index);
+            }
+            None => self.link_alone(index),
+        }
+        Ok(())
+    }
+
+    // Removes the current element and returns it; the element after it becomes current.
+    // Returns None if the list is empty.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let prev = self.prev?;
+        let current = self.next(prev);
+        if current == prev {
+            self.prev = None;
+        } else {
+            self.slots.slots_mut()[prev].next = Some(self.next(current));
+        }
+        self.len -= 1;
+        Some(StaticNode::release(
+            self.slots.slots_mut(),
+            &mut self.free,
+            current,
+        ))
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        while self.remove_current().is_some() {}
+    }
+
+    // Returns an iterator over one lap of the list, starting at the current element.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            slots: self.slots.slots(),
+            current: self.current_slot(),
+            remaining: self.len,
+        }
+    }
+
+    // Returns an iterator that goes round the list forever, starting at the current element.
+    // It yields nothing if the list is empty. The current position does not move.
+    pub fn cycle_iter(&self) -> CycleIter<'_, T> {
+        CycleIter {
+            slots: self.slots.slots(),
+            current: self.current_slot(),
+        }
+    }
+
+    fn current_slot(&self) -> Option<usize> {
+        self.prev.map(|prev| self.next(prev))
+    }
+
+    // Returns the slot after a linked slot. Linked slots always have one, as the chain is a cycle.
+    fn next(&self, index: usize) -> usize {
+        self.slots.slots()[index]
+            .next
+            .expect("circular list slot must be linked")
+    }
+
+    fn data(&self, index: usize) -> &T {
+        self.slots.slots()[index]
+            .data
+            .as_ref()
+            .expect("linked slot must be occupied")
+    }
+
+    // Takes a slot off the free list, growing the storage first if it can and has to.
+    fn alloc(&mut self, data: T) -> Result<usize, T> {
+        if self.free.is_none() {
+            self.slots.grow(&mut self.free);
+        }
+        StaticNode::alloc(self.slots.slots_mut(), &mut self.free, data)
+    }
+
+    // Links an allocated slot after a linked one.
+    fn link_after(&mut self, at: usize, index: usize) {
+        let next = self.slots.slots_mut()[at].next.replace(index);
+        self.slots.slots_mut()[index].next = next;
+        self.len += 1;
+    }
+
+    // Makes an allocated slot the only element, linked to itself.
+    fn link_alone(&mut self, index: usize) {
+        self.slots.slots_mut()[index].next = Some(index);
+        self.prev = Some(index);
+        self.len = 1;
+    }
+}
+
+impl<T, S: SlotStorage<T>> Default for CircularList<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Lists one lap, starting at the current element.
+impl<T: fmt::Debug, S: SlotStorage<T>> fmt::Debug for CircularList<T, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Builds a list holding the elements in iteration order, with the first one current.
+// Panics if a fixed size list runs out of slots.
+impl<T, S: SlotStorage<T>> FromIterator<T> for CircularList<T, S> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = CircularList::new();
+        for data in iter {
+            if list.insert_before_current(data).is_err() {
+                panic!("circular list is full");
+            }
+        }
+        list
+    }
+}
+
+// Iterator over one lap of a CircularList, starting at the current element.
+pub struct Iter<'a, T> {
+    slots: &'a [StaticNode<T>],
+    current: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let node = &self.slots[self.current?];
+        self.current = node.next;
+        self.remaining -= 1;
+        node.data.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Iterator that goes round a CircularList forever.
+pub struct CycleIter<'a, T> {
+    slots: &'a [StaticNode<T>],
+    current: Option<usize>,
+}
+
+impl<'a, T> Iterator for CycleIter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = &self.slots[self.current?];
+        self.current = node.next;
+        node.data.as_ref()
+    }
+}
@@ -0,0 +1,237 @@
+use std::fmt;
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+use crate::epoch::{Collector, Guard};
+
+// A sorted set of elements that many threads can use at once without locks.
+// This is Harris's lock-free linked list: an element is deleted by first marking its node's
+// next pointer (the low bit of the address), which stops anyone linking after it, and then
+// unlinking it. Any traversal that meets a marked node helps unlink it. Unlinked nodes are
+// freed through the epoch collector once no thread can still be reading them.
+// Every operation is O(n) in the number of elements.
+pub struct ConcurrentList<T> {
+    head: AtomicPtr<Node<T>>,
+    collector: Collector,
+}
+
+struct Node<T> {
+    data: T,
+    // The next node, with the low bit set once this node has been deleted.
+    next: AtomicPtr<Node<T>>,
+}
+
+// Nodes are reached from several threads and freed by whichever thread collects them.
+unsafe impl<T: Send + Sync> Send for ConcurrentList<T> {}
+unsafe impl<T: Send + Sync> Sync for ConcurrentList<T> {}
+
+fn is_marked<T>(ptr: *mut Node<T>) -> bool {
+    ptr.addr() & 1 == 1
+}
+
+fn marked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr | 1)
+}
+
+fn unmarked<T>(ptr: *mut Node<T>) -> *mut Node<T> {
+    ptr.map_addr(|addr| addr & !1)
+}
+
+impl<T: Ord> ConcurrentList<T> {
+    pub fn new() -> Self {
+        ConcurrentList {
+            head: AtomicPtr::new(ptr::null_mut()),
+            collector: Collector::new(),
+        }
+    }
+
+    // Inserts data in order unless an equal element is already present.
+    // Returns true if data was inserted, false if it was already in the list.
+    // data: T: The element to insert.
+    pub fn insert(&self, data: T) -> bool {
+        let guard = self.collector.pin();
+        let node = Box::into_raw(Box::new(Node {
+            data,
+            next: AtomicPtr::new(ptr::null_mut()),
+        }));
+
+        // Only read while the node is still private to this thread.
+        let data = unsafe { &(*node).data };
+
+        loop {
+            let (prev, current) = self.search(data, &guard);
+            if let Some(existing) = unsafe { current.as_ref() }
+                && existing.data == *data
+            {
+                // Never published, so it can be freed straight away.
+                drop(unsafe { Box::from_raw(node) });
+                return false;
+            }
+
+            unsafe { (*node).next.store(current, Ordering::SeqCst) };
+            if prev
+                .compare_exchange(current, node, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                return true;
+            }
+        }
+    }
+
+    // Deletes the element equal to data.
+    // Returns true if it was found and deleted, false if it was not in the list.
+    // data: &T: The element to delete.
+    pub fn delete(&self, data: &T) -> bool {
+        let guard = self.collector.pin();
+        loop {
+            let (prev, current) = self.search(data, &guard);
+            let Some(node) = (unsafe { current.as_ref() }) else {
+                return false;
+            };
+            if node.data != *data {
+                return false;
+            }
+
+            let next = node.next.load(Ordering::SeqCst);
+            if is_marked(next) {
+                // Another thread is deleting it; search again to help and see the result.
+                continue;
+            }
+            // Marking is the linearization point: from here on the element is gone.
+            if node
+                .next
+                .compare_exchange(next, marked(next), Ordering::SeqCst, Ordering::SeqCst)
+                .is_err()
+            {
+                continue;
+            }
+
+            if prev
+                .compare_exchange(current, next, Ordering::SeqCst, Ordering::SeqCst)
+                .is_ok()
+            {
+                unsafe { guard.retire(current) };
+            } else {
+                // Something changed around the node; a search unlinks it on the way past.
+                self.search(data, &guard);
+            }
+            return true;
+        }
+    }
+
+    // Returns true if an element equal to data is in the list. Never writes to shared memory.
+    // data: &T: The element to look for.
+    pub fn contains(&self, data: &T) -> bool {
+        let _guard = self.collector.pin();
+        let mut current = self.head.load(Ordering::SeqCst);
+        while let Some(node) = unsafe { current.as_ref() } {
+            let next = node.next.load(Ordering::SeqCst);
+            if node.data >= *data {
+                return node.data == *data && !is_marked(next);
+            }
+            current = unmarked(next);
+        }
+        false
+    }
+
+    // Returns the number of elements not marked as deleted. Other threads may change
+    // the list while it is counted, so the result is only exact when nobody else is.
+    pub fn len(&self) -> usize {
+        let _guard = self.collector.pin();
+        let mut len = 0;
+        let mut current = self.head.load(Ordering::SeqCst);
+        while let Some(node) = unsafe { current.as_ref() } {
+            let next = node.next.load(Ordering::SeqCst);
+            if !is_marked(next) {
+                len += 1;
+            }
+            current = unmarked(next);
+        }
+        len
+    }
+
+    // Returns true if the list has no elements, with the same caveat as len.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Finds where data belongs: the link pointing at the first node not less than data,
+    // and that node (null at the end of the list). Marked nodes met on the way are unlinked
+    // and retired. The returned link belongs to the head or to an unmarked node.
+    fn search<'g>(
+        &'g self,
+        data: &T,
+        guard: &'g Guard<'_>,
+    ) -> (&'g AtomicPtr<Node<T>>, *mut Node<T>) {
+        'retry: loop {
+            let mut prev = &self.head;
+            let mut current = prev.load(Ordering::SeqCst);
+            loop {
+                let Some(node) = (unsafe { current.as_ref() }) else {
+                    return (prev, current);
+                };
+                let next = node.next.load(Ordering::SeqCst);
+                if is_marked(next) {
+                    let next = unmarked(next);
+                    // Fails if prev was marked or changed; start again from the head.
+                    if prev
+                        .compare_exchange(current, next, Ordering::SeqCst, Ordering::SeqCst)
+                        .is_err()
+                    {
+                        continue 'retry;
+                    }
+                    unsafe { guard.retire(current) };
+                    current = next;
+                } else {
+                    if node.data >= *data {
+                        return (prev, current);
+                    }
+                    prev = &node.next;
+                    current = next;
+                }
+            }
+        }
+    }
+}
+
+impl<T: Ord + Clone> ConcurrentList<T> {
+    // Returns the elements in order. Elements inserted or deleted while this runs
+    // may or may not be included.
+    pub fn to_vec(&self) -> Vec<T> {
+        let _guard = self.collector.pin();
+        let mut items = Vec::new();
+        let mut current = self.head.load(Ordering::SeqCst);
+        while let Some(node) = unsafe { current.as_ref() } {
+            let next = node.next.load(Ordering::SeqCst);
+            if !is_marked(next) {
+                items.push(node.data.clone());
+            }
+            current = unmarked(next);
+        }
+        items
+    }
+}
+
+impl<T: Ord> Default for ConcurrentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + Clone + fmt::Debug> fmt::Debug for ConcurrentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+// No other thread can hold a reference any more, so every node still linked is freed
+// here, including marked ones, and the collector frees the ones already retired.
+impl<T> Drop for ConcurrentList<T> {
+    fn drop(&mut self) {
+        let mut current = *self.head.get_mut();
+        while !current.is_null() {
+            let mut node = unsafe { Box::from_raw(current) };
+            current = unmarked(*node.next.get_mut());
+        }
+    }
+}
@@ -0,0 +1,118 @@
+// Graphviz DOT export for looking at list structure, e.g. `dot -Tsvg list.dot`.
+// Nodes are labelled with the Debug output of their data.
+
+use std::fmt::{self, Write};
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::static_list::StaticLinkedList;
+
+impl<T: fmt::Debug> DynamicLinkedList<T> {
+    // Renders the list as a DOT graph: one box per node, in order, joined by next edges.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph DynamicLinkedList {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [shape=box];\n");
+        dot.push_str("    head [shape=plaintext];\n");
+
+        for (i, data) in self.iter().enumerate() {
+            let _ = writeln!(dot, "    n{i} [label=\"{}\"];", escape(data));
+        }
+        if !self.is_empty() {
+            dot.push_str("    head -> n0;\n");
+        }
+        for i in 1..self.len() {
+            let _ = writeln!(dot, "    n{} -> n{i};", i - 1);
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> StaticLinkedList<T, N> {
+    // Renders the whole slot array as a DOT graph. Every slot is drawn with its index;
+    // live slots are solid and linked by solid next edges from head, free slots are dashed
+    // and linked by dashed edges from free. A slot that is on neither chain, or on both,
+    // is drawn in red so leaked and orphaned slots stand out.
+    pub fn to_dot(&self) -> String {
+        let next: Vec<Option<usize>> = self.slots().map(|(_, next)| next).collect();
+        let live = chain_membership(&next, self.head_slot());
+        let free = chain_membership(&next, self.free_slot());
+
+        let mut dot = String::new();
+        dot.push_str("digraph StaticLinkedList {\n");
+        dot.push_str("    rankdir=LR;\n");
+        dot.push_str("    node [shape=box];\n");
+        dot.push_str("    head [shape=plaintext];\n");
+        dot.push_str("    free [shape=plaintext];\n");
+
+        for (i, (data, _)) in self.slots().enumerate() {
+            let label = match data {
+                Some(data) => format!("{i}: {}", escape(data)),
+                None => format!("{i}: free"),
+            };
+            let style = match (live[i], free[i]) {
+                (true, false) => "",
+                (false, true) => ", style=dashed",
+                _ => ", color=red",
+            };
+            let _ = writeln!(dot, "    s{i} [label=\"{label}\"{style}];");
+        }
+
+        write_chain(&mut dot, &next, "head", self.head_slot(), "");
+        write_chain(&mut dot, &next, "free", self.free_slot(), " [style=dashed]");
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+// Marks the slots reachable from start. Stops at the first out of range
+// index or revisited slot, so a corrupted chain cannot loop forever.
+// next: &[Option<usize>]: The next index of every slot.
+fn chain_membership(next: &[Option<usize>], start: Option<usize>) -> Vec<bool> {
+    let mut seen = vec![false; next.len()];
+    let mut current = start;
+    while let Some(i) = current {
+        if i >= next.len() || seen[i] {
+            break;
+        }
+        seen[i] = true;
+        current = next[i];
+    }
+    seen
+}
+
+// Writes the edges of the chain starting at start, beginning with an edge from the named pointer.
+// An edge into an out of range index or back into the chain is still drawn, then the walk stops.
+fn write_chain(
+    dot: &mut String,
+    next: &[Option<usize>],
+    name: &str,
+    start: Option<usize>,
+    attributes: &str,
+) {
+    let Some(first) = start else {
+        return;
+    };
+    let _ = writeln!(dot, "    {name} -> s{first}{attributes};");
+
+    let mut seen = vec![false; next.len()];
+    let mut current = first;
+    while current < next.len() && !seen[current] {
+        seen[current] = true;
+        let Some(following) = next[current] else {
+            break;
+        };
+        let _ = writeln!(dot, "    s{current} -> s{following}{attributes};");
+        current = following;
+    }
+}
+
+// Formats data with Debug and escapes it for use inside a quoted DOT label.
+fn escape<T: fmt::Debug>(data: &T) -> String {
+    format!("{data:?}")
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+}
@@ -1,44 +1,719 @@
+use std::cmp::Ordering;
+use std::ptr;
+
+use crate::invariants::{Chain, InvariantViolation};
+use crate::storage::ListStorage;
+
 // This struct defines a dynamic linked list data structure in Rust.
 pub struct Node<T> {
     pub data: T,
     pub next: Option<Box<Node<T>>>,
 }
 
+// An owning link to the next node, or None at the end of a chain.
+type Link<T> = Option<Box<Node<T>>>;
+
 // The DynamicLinkedList struct represents a linked list data structure.
-// It contains a head pointer that points to the first node in the list.
+// It contains a head pointer that points to the first node in the list,
+// a raw tail pointer so appending is O(1), and the number of nodes.
+// The tail pointer is null whenever the list is empty.
 pub struct DynamicLinkedList<T> {
-    pub head: Option<Box<Node<T>>>,
+    head: Option<Box<Node<T>>>,
+    tail: *mut Node<T>,
+    len: usize,
+}
+
+// The raw tail pointer only ever points into nodes owned by `head`,
+// so the list is as thread safe as the data it holds.
+unsafe impl<T: Send> Send for DynamicLinkedList<T> {}
+unsafe impl<T: Sync> Sync for DynamicLinkedList<T> {}
+
+// Methods that only restructure the list and never compare or copy data,
+// so they are available for any type T.
+impl<T> DynamicLinkedList<T> {
+    pub fn new() -> Self {
+        DynamicLinkedList {
+            head: None,
+            tail: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    // Returns the number of nodes in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no nodes.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns a reference to the first node, or None if the list is empty.
+    // The chain can be walked through each node's next field; it is read only so that
+    // the tail pointer and length stay in step with it.
+    pub fn head(&self) -> Option<&Node<T>> {
+        self.head.as_deref()
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            current: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+
+    // Adds a node holding data at the end of the list in O(1) using the tail pointer.
+    pub(crate) fn push_back(&mut self, data: T) {
+        let new_node = Box::new(Node { data, next: None });
+
+        let slot = if self.tail.is_null() {
+            &mut self.head
+        } else {
+            // The tail pointer is valid whenever the list is non-empty.
+            unsafe { &mut (*self.tail).next }
+        };
+        *slot = Some(new_node);
+        self.tail = slot.as_deref_mut().unwrap() as *mut Node<T>;
+        self.len += 1;
+        self.check_invariants();
+    }
+
+    // Adds a node holding data at the front of the list.
+    pub(crate) fn push_front(&mut self, data: T) {
+        let new_node = Box::new(Node {
+            data,
+            next: self.head.take(),
+        });
+        self.head = Some(new_node);
+        if self.tail.is_null() {
+            self.tail = self.head.as_deref_mut().unwrap() as *mut Node<T>;
+        }
+        self.len += 1;
+        self.check_invariants();
+    }
+
+    // Unlinks the node at the specified index and returns its data.
+    // Returns None if the index is out of bounds.
+    pub(crate) fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut link = &mut self.head;
+        for _ in 0..index {
+            link = &mut link.as_mut()?.next;
+        }
+
+        let mut node = link.take()?;
+        *link = node.next.take();
+        self.len -= 1;
+        if index == self.len {
+            // The old tail was removed, so the node before it is the new tail.
+            self.reset_tail();
+        }
+        self.check_invariants();
+        Some(node.data)
+    }
+
+    // Walks the list to find the last node and points the tail at it.
+    fn reset_tail(&mut self) {
+        self.tail = ptr::null_mut();
+        let mut current = self.head.as_deref_mut();
+        while let Some(node) = current {
+            if node.next.is_none() {
+                self.tail = node as *mut Node<T>;
+                return;
+            }
+            current = node.next.as_deref_mut();
+        }
+    }
+
+    // Returns a mutable reference to the node at the specified index, or None if out of bounds.
+    fn node_mut(&mut self, index: usize) -> Option<&mut Node<T>> {
+        let mut current = self.head.as_deref_mut();
+        for _ in 0..index {
+            current = current?.next.as_deref_mut();
+        }
+        current
+    }
+
+    // Moves all nodes of other to the end of this list in O(1), leaving other empty.
+    // other: &mut Self: The list whose nodes are moved; it is empty afterwards.
+    pub fn append(&mut self, other: &mut Self) {
+        if other.is_empty() {
+            return;
+        }
+        if self.is_empty() {
+            std::mem::swap(self, other);
+            return;
+        }
+
+        // Both lists are non-empty, so both tail pointers are valid.
+        unsafe {
+            (*self.tail).next = other.head.take();
+        }
+        self.tail = other.tail;
+        self.len += other.len;
+        other.tail = ptr::null_mut();
+        other.len = 0;
+        self.check_invariants();
+    }
+
+    // Splits the list in two at the given index.
+    // Returns a new list holding the nodes from index at onwards; this list keeps [0, at).
+    // If at is past the end of the list nothing is split off and an empty list is returned.
+    // at: usize: The index of the first node that moves to the new list.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        if at >= self.len {
+            return Self::new();
+        }
+        if at == 0 {
+            return std::mem::take(self);
+        }
+
+        let old_tail = self.tail;
+        let old_len = self.len;
+        let node = self.node_mut(at - 1).unwrap();
+        let rest = node.next.take();
+        let new_tail = node as *mut Node<T>;
+
+        self.tail = new_tail;
+        self.len = at;
+        let rest = DynamicLinkedList {
+            head: rest,
+            tail: old_tail,
+            len: old_len - at,
+        };
+        self.check_invariants();
+        rest.check_invariants();
+        rest
+    }
+
+    // Moves all nodes of other into this list so the first of them ends up at index.
+    // Returns true if the nodes were spliced in, false if the index is out of bounds
+    // (in which case other is left untouched).
+    // index: usize: The position the first node of other will occupy.
+    // other: &mut Self: The list whose nodes are moved; it is empty afterwards.
+    pub fn splice_at(&mut self, index: usize, other: &mut Self) -> bool {
+        if index > self.len {
+            return false;
+        }
+        if index == self.len {
+            self.append(other);
+            return true;
+        }
+        if other.is_empty() {
+            return true;
+        }
+
+        let other_head = other.head.take();
+        let other_tail = other.tail;
+        let other_len = other.len;
+        other.tail = ptr::null_mut();
+        other.len = 0;
+
+        let link = if index == 0 {
+            &mut self.head
+        } else {
+            &mut self.node_mut(index - 1).unwrap().next
+        };
+        // other_tail is valid because the nodes it points into are still owned by other_head.
+        unsafe {
+            (*other_tail).next = link.take();
+        }
+        *link = other_head;
+        self.len += other_len;
+        self.check_invariants();
+        true
+    }
+
+    // Shortens the list to the first len nodes, dropping the rest.
+    // If len is greater than or equal to the current length this has no effect.
+    // len: usize: The number of nodes to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+        if len == 0 {
+            self.clear();
+            return;
+        }
+
+        let node = self.node_mut(len - 1).unwrap();
+        let rest = node.next.take();
+        self.tail = node as *mut Node<T>;
+        self.len = len;
+        drop_chain(rest);
+        self.check_invariants();
+    }
+
+    // Removes every node from the list.
+    pub fn clear(&mut self) {
+        drop_chain(self.head.take());
+        self.tail = ptr::null_mut();
+        self.len = 0;
+    }
+}
+
+// Sorting. The nodes are relinked in place with a bottom-up merge sort, so no data is
+// moved or cloned and no extra memory is allocated.
+impl<T> DynamicLinkedList<T> {
+    // Sorts the list in ascending order. The sort is stable.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    // Sorts the list by the key extracted from each element. The sort is stable.
+    // f: FnMut(&T) -> K: Extracts the key to compare elements by.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // Sorts the list with a comparator function in O(n log n). The sort is stable:
+    // elements that compare equal keep their original order.
+    // cmp: FnMut(&T, &T) -> Ordering: Compares two elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        let len = self.len;
+        let mut sort = SortGuard {
+            rest: self.head.take(),
+            list: self,
+            merged: None,
+            left: None,
+            right: None,
+        };
+        let mut width = 1;
+        while width < len {
+            // Merge neighbouring runs of width nodes into runs of 2 * width nodes.
+            let mut out = &mut sort.merged;
+            while sort.rest.is_some() {
+                sort.left = sort.rest.take();
+                sort.right = split_run(&mut sort.left, width);
+                sort.rest = split_run(&mut sort.right, width);
+                out = merge_runs(out, &mut sort.left, &mut sort.right, &mut cmp);
+            }
+            sort.rest = sort.merged.take();
+            width *= 2;
+        }
+
+        drop(sort);
+        self.check_invariants();
+    }
+
+    // Returns true if the elements are in ascending order.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().is_sorted()
+    }
+}
+
+// Owns every node of a list while sort_by relinks them, split into the runs merged so far,
+// the two runs being merged and the rest. Dropping it chains the pieces back together in that
+// order and hands them to the list, so if the comparator panics no node is lost and the
+// tail pointer and length still describe the list, though its order is unspecified.
+struct SortGuard<'a, T> {
+    list: &'a mut DynamicLinkedList<T>,
+    merged: Link<T>,
+    left: Link<T>,
+    right: Link<T>,
+    rest: Link<T>,
+}
+
+impl<T> Drop for SortGuard<'_, T> {
+    fn drop(&mut self) {
+        let mut end = &mut self.list.head;
+        for piece in [&mut self.merged, &mut self.left, &mut self.right, &mut self.rest] {
+            *end = piece.take();
+            while let Some(node) = end {
+                end = &mut node.next;
+            }
+        }
+        self.list.reset_tail();
+    }
+}
+
+// Cuts a chain after (at most) n nodes.
+// Returns the remainder of the chain.
+fn split_run<T>(chain: &mut Link<T>, n: usize) -> Link<T> {
+    let mut link = chain;
+    for _ in 0..n {
+        match link {
+            Some(node) => link = &mut node.next,
+            None => break,
+        }
+    }
+    link.take()
+}
+
+// Merges two sorted runs onto the empty link out, taking from left on ties to keep the sort stable.
+// Both runs are emptied; a node is always in exactly one of out, left or right, even mid-merge.
+// Returns the empty link after the last merged node.
+fn merge_runs<'a, T, F: FnMut(&T, &T) -> Ordering>(
+    mut out: &'a mut Link<T>,
+    left: &mut Link<T>,
+    right: &mut Link<T>,
+    cmp: &mut F,
+) -> &'a mut Link<T> {
+    loop {
+        let take_left = match (left.as_deref(), right.as_deref()) {
+            (Some(a), Some(b)) => cmp(&b.data, &a.data) != Ordering::Less,
+            (Some(_), None) => true,
+            (None, Some(_)) => false,
+            (None, None) => return out,
+        };
+        let source = if take_left { &mut *left } else { &mut *right };
+        let mut node = source.take().unwrap();
+        *source = node.next.take();
+        out = &mut out.insert(node).next;
+    }
+}
+
+// Classic list algorithms working on the Node<T>::next chain.
+impl<T> DynamicLinkedList<T> {
+    // Reverses the order of the nodes by relinking them; no data is moved.
+    pub fn reverse(&mut self) {
+        // The current head becomes the tail.
+        let new_tail = match self.head.as_deref_mut() {
+            Some(node) => node as *mut Node<T>,
+            None => return,
+        };
+
+        let mut reversed: Link<T> = None;
+        let mut rest = self.head.take();
+        while let Some(mut node) = rest {
+            rest = node.next.take();
+            node.next = reversed;
+            reversed = Some(node);
+        }
+        self.head = reversed;
+        self.tail = new_tail;
+        self.check_invariants();
+    }
+
+    // Rotates the list so the node at index k becomes the head.
+    // k is taken modulo the length, so any k is accepted.
+    // k: usize: The number of nodes moved from the front to the back.
+    pub fn rotate_left(&mut self, k: usize) {
+        if self.len < 2 || k.is_multiple_of(self.len) {
+            return;
+        }
+        let mut rest = self.split_off(k % self.len);
+        rest.append(self);
+        *self = rest;
+    }
+
+    // Rotates the list so the last k nodes move to the front.
+    // k is taken modulo the length, so any k is accepted.
+    // k: usize: The number of nodes moved from the back to the front.
+    pub fn rotate_right(&mut self, k: usize) {
+        if self.len < 2 {
+            return;
+        }
+        self.rotate_left(self.len - k % self.len);
+    }
+
+    // Removes consecutive duplicate elements, keeping the first of each run.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    // Removes consecutive elements that map to the same key, keeping the first of each run.
+    // key: FnMut(&T) -> K: Extracts the key to compare neighbouring elements by.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // Removes consecutive elements for which same_bucket returns true, keeping the first of each run.
+    // same_bucket: FnMut(&T, &T) -> bool: Called with the candidate for removal and the element kept before it.
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same_bucket: F) {
+        let mut current = match self.head.as_deref_mut() {
+            Some(node) => node,
+            None => return,
+        };
+        // Compare before unlinking anything, so if same_bucket panics the chain is still whole
+        // and the tail pointer and length still match it.
+        while let Some(next) = current.next.as_deref() {
+            if same_bucket(&next.data, &current.data) {
+                let mut next = current.next.take().unwrap();
+                current.next = next.next.take();
+                self.len -= 1;
+            } else {
+                current = current.next.as_deref_mut().unwrap();
+            }
+        }
+        self.tail = current as *mut Node<T>;
+        self.check_invariants();
+    }
+
+    // Swaps the data held at indices i and j.
+    // Returns true if both indices are in bounds, false otherwise (and the list is unchanged).
+    // i: usize, j: usize: The positions to swap.
+    pub fn swap(&mut self, i: usize, j: usize) -> bool {
+        if i >= self.len || j >= self.len {
+            return false;
+        }
+        let (low, high) = if i < j { (i, j) } else { (j, i) };
+        if low == high {
+            return true;
+        }
+
+        // Borrow the low node's data and its next link separately so both can be held at once.
+        let node = self.node_mut(low).unwrap();
+        let low_data = &mut node.data;
+        let mut current = node.next.as_deref_mut();
+        for _ in low + 1..high {
+            current = current.and_then(|node| node.next.as_deref_mut());
+        }
+        std::mem::swap(low_data, &mut current.unwrap().data);
+        true
+    }
+
+    // Returns the element k positions from the back (k = 0 is the last element),
+    // or None if the list is too short. Uses two pointers k nodes apart, so the
+    // list is walked once without relying on its length.
+    // k: usize: The distance from the last element.
+    pub fn nth_from_back(&self, k: usize) -> Option<&T> {
+        let mut lead = self.head.as_deref()?;
+        for _ in 0..k {
+            lead = lead.next.as_deref()?;
+        }
+
+        let mut trail = self.head.as_deref()?;
+        while let Some(next) = lead.next.as_deref() {
+            lead = next;
+            trail = trail.next.as_deref()?;
+        }
+        Some(&trail.data)
+    }
+
+    // Returns the middle element, or None if the list is empty. For an even number
+    // of elements this is the second of the two middle ones. Uses a slow pointer
+    // and a fast pointer moving twice as quickly.
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self.head.as_deref()?;
+        let mut fast = self.head.as_deref()?;
+        while let Some(next) = fast.next.as_deref() {
+            slow = slow.next.as_deref()?;
+            match next.next.as_deref() {
+                Some(after) => fast = after,
+                None => break,
+            }
+        }
+        Some(&slow.data)
+    }
+
+    // Returns true if following next links ever revisits a node (Floyd's cycle detection).
+    // A correctly built list never has a cycle; this is a debugging aid.
+    pub fn has_cycle(&self) -> bool {
+        self.cycle_start().is_some()
+    }
+
+    // Returns the position of the node where following next links starts repeating,
+    // or None if the chain ends. The slow and fast pointers meet inside the cycle;
+    // a pointer from the head and one from the meeting point then meet at its start.
+    fn cycle_start(&self) -> Option<usize> {
+        let mut slow = self.head.as_deref()?;
+        let mut fast = self.head.as_deref()?;
+        loop {
+            fast = fast.next.as_deref()?.next.as_deref()?;
+            slow = slow.next.as_deref()?;
+            if ptr::eq(slow, fast) {
+                break;
+            }
+        }
+
+        let mut from_head = self.head.as_deref()?;
+        let mut position = 0;
+        while !ptr::eq(from_head, slow) {
+            from_head = from_head.next.as_deref()?;
+            slow = slow.next.as_deref()?;
+            position += 1;
+        }
+        Some(position)
+    }
+
+    // Checks the structural invariants of the list: the chain of nodes ends, the cached
+    // length matches the number of nodes and the tail pointer points at the last node.
+    // A tail pointer that points at no node of the list is reported with tail: None.
+    // Returns the first violation found. Runs in O(n).
+    pub fn validate(&self) -> Result<(), InvariantViolation> {
+        if let Some(slot) = self.cycle_start() {
+            return Err(InvariantViolation::Cycle {
+                chain: Chain::Live,
+                slot,
+            });
+        }
+
+        let mut actual = 0;
+        let mut tail = None;
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            if ptr::eq(node, self.tail) {
+                tail = Some(actual);
+            }
+            actual += 1;
+            current = node.next.as_deref();
+        }
+
+        if actual != self.len {
+            return Err(InvariantViolation::LengthMismatch {
+                cached: self.len,
+                actual,
+            });
+        }
+        let last = actual.checked_sub(1);
+        if tail != last || (last.is_none() && !self.tail.is_null()) {
+            return Err(InvariantViolation::TailMismatch { tail, last });
+        }
+        Ok(())
+    }
+
+    // Panics if the list breaks an invariant. Only does anything with the
+    // debug-invariants feature, so it is free to call after every mutation.
+    #[inline]
+    fn check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(violation) = self.validate() {
+            panic!("DynamicLinkedList invariant violated: {violation}");
+        }
+    }
+}
+
+// Drops a chain of nodes one at a time so long lists cannot overflow the stack
+// through recursive Box drops.
+fn drop_chain<T>(mut link: Option<Box<Node<T>>>) {
+    while let Some(mut node) = link {
+        link = node.next.take();
+    }
+}
+
+impl<T> Default for DynamicLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Drop for DynamicLinkedList<T> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T> ListStorage<T> for DynamicLinkedList<T> {
+    type Iter<'a>
+        = Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter(&self) -> Iter<'_, T> {
+        DynamicLinkedList::iter(self)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.node_mut(index).map(|node| &mut node.data)
+    }
+
+    fn push_back(&mut self, data: T) -> Result<(), T> {
+        DynamicLinkedList::push_back(self, data);
+        Ok(())
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) -> Result<(), T> {
+        if index > self.len {
+            return Err(data);
+        }
+        if index == 0 {
+            self.push_front(data);
+            return Ok(());
+        }
+        if index == self.len {
+            DynamicLinkedList::push_back(self, data);
+            return Ok(());
+        }
+
+        let node = self.node_mut(index - 1).unwrap();
+        node.next = Some(Box::new(Node {
+            data,
+            next: node.next.take(),
+        }));
+        self.len += 1;
+        self.check_invariants();
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        self.remove(0)
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<T> {
+        self.remove(index)
+    }
+
+    fn clear(&mut self) {
+        DynamicLinkedList::clear(self);
+    }
+}
+
+// Iterator over references to the data in a DynamicLinkedList, from head to tail.
+pub struct Iter<'a, T> {
+    current: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let node = self.current?;
+        self.current = node.next.as_deref();
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a DynamicLinkedList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
 }
 
 //Implementing the DynamicLinkedList struct with a generic type T.
 //The struct is generic, meaning it can hold any type T.
 impl<T: PartialEq + Clone> DynamicLinkedList<T> {
-    pub fn new() -> Self {
-        DynamicLinkedList { head: None }
-    }
-    
     //Inserts a new node at the end of the list.
-    //If the data already exists, it will not be inserted again.
+    //Duplicates are allowed; use UniqueList when each value must appear only once.
     //mut self: This indicates that the method can modify the instance of the struct.
     //data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn insert(&mut self, data: T) {
-        let new_node = Box::new(Node { data, next: None });
-    
-        match self.head.as_mut() {
-            None => self.head = Some(new_node),
-            Some(mut node) => {
-                // Traverse safely by getting a mutable reference to the tail node
-                let mut current = &mut **node as *mut Node<T>;
-                unsafe {
-                    while let Some(ref mut next) = (*current).next {
-                        current = next.as_mut() as *mut Node<T>;
-                    }
-                    (*current).next = Some(new_node);
-                }
-            }
-        }
+        self.push_back(data);
     }
-    
+
 
     //Retrieves the data at the specified index in the list.
     //Returns an Option<T>, which is Some(data) if the index is valid, or None if it is out of bounds.
@@ -63,30 +738,8 @@ impl<T: PartialEq + Clone> DynamicLinkedList<T> {
     //index: usize: This is the index where we want to insert the new node.
     //data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn insert_at_index(&mut self, index: usize, data: T) {
-        if index == 0 {
-            let new_node = Box::new(Node {
-                data,
-                next: self.head.take(),
-            });
-            self.head = Some(new_node);
-            return;
-        }
-
-        let mut current = self.head.as_mut();
-        for _ in 0..index - 1 {
-            match current {
-                Some(node) => current = node.next.as_mut(),
-                None => return, // Out of bounds
-            }
-        }
-
-        if let Some(node) = current {
-            let new_node = Box::new(Node {
-                data,
-                next: node.next.take(),
-            });
-            node.next = Some(new_node);
-        }
+        // An out of bounds index leaves the list unchanged
+        let _ = self.insert_at(index, data);
     }
 
     // Deletes the first occurrence of the specified data from the list.
@@ -94,54 +747,20 @@ impl<T: PartialEq + Clone> DynamicLinkedList<T> {
     // self: This indicates that the method is borrowing the instance of the struct.
     // data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn delete_element(&mut self, data: T) -> bool {
-        let mut link = &mut self.head;
-    
-        while link.is_some() {
-            if link.as_ref().unwrap().data == data {
-                let next = link.as_mut().unwrap().next.take();
-                *link = next;
-                return true;
-            } else {
-                link = &mut link.as_mut().unwrap().next;
-            }
+        match self.iter().position(|item| *item == data) {
+            Some(index) => self.remove(index).is_some(),
+            None => false,
         }
-    
-        false
     }
-    
-    
+
+
 
     // Deletes the node at the specified index from the list.
     // Returns true if the node was found and deleted, false otherwise.
     // self: This indicates that the method is borrowing the instance of the struct.
     // index: usize: This is the index of the node we want to delete from the list.
     pub fn delete_at_index(&mut self, index: usize) -> bool {
-        if index == 0 {
-            if let Some(node) = self.head.take() {
-                self.head = node.next;
-                return true;
-            } else {
-                return false;
-            }
-        }
-
-        let mut current = self.head.as_mut();
-        for _ in 0..index - 1 {
-            match current {
-                Some(node) => current = node.next.as_mut(),
-                None => return false,
-            }
-        }
-
-        if let Some(node) = current {
-            let next = node.next.take();
-            if let Some(mut next_node) = next {
-                node.next = next_node.next.take();
-                return true;
-            }
-        }
-
-        false
+        self.remove(index).is_some()
     }
 
     // Updates the first occurrence of the specified data with new data.
@@ -203,4 +822,19 @@ impl<T: PartialEq + Clone> DynamicLinkedList<T> {
 
 
 
-}
\ No newline at end of file
+}
+
+// Test-only hooks that break the cached bookkeeping so validate can be exercised.
+#[cfg(test)]
+impl<T> DynamicLinkedList<T> {
+    pub(crate) fn corrupt_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    pub(crate) fn corrupt_tail_to_head(&mut self) {
+        self.tail = self
+            .head
+            .as_deref_mut()
+            .map_or(ptr::null_mut(), |node| node as *mut Node<T>);
+    }
+}
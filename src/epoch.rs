@@ -0,0 +1,196 @@
+// Epoch based memory reclamation for the lock-free structures in this crate.
+//
+// A thread pins the collector before it reads shared nodes and unpins when it is done. Pinning
+// records the global epoch the thread saw. A node that has been unlinked is retired rather than
+// freed: it is tagged with the global epoch at the time and freed once the global epoch is two
+// further on. The global epoch only advances when every pinned thread has seen the current one,
+// so two advances mean every thread that could have been holding the node has since unpinned.
+//
+// Threads are not registered up front. Each pin claims a free participant record from a
+// lock-free list of records, adding a new one if all are busy, and gives it back on unpin.
+// A record keeps the garbage retired through it, so work left by one pin is finished by a later one.
+
+use std::cell::UnsafeCell;
+use std::ptr;
+use std::sync::atomic::{AtomicBool, AtomicPtr, AtomicUsize, Ordering, fence};
+
+// How much garbage a record collects before a retire tries to free some.
+const COLLECT_THRESHOLD: usize = 64;
+
+// The state behind one or more pinned sections: the global epoch and every participant record.
+pub(crate) struct Collector {
+    epoch: AtomicUsize,
+    participants: AtomicPtr<Participant>,
+}
+
+struct Participant {
+    // 0 when not pinned, otherwise the pinned epoch shifted left by one with the low bit set.
+    epoch: AtomicUsize,
+    in_use: AtomicBool,
+    // Only touched by the pin that has claimed the record.
+    garbage: UnsafeCell<Vec<Deferred>>,
+    // Set before the record is published and never changed afterwards.
+    next: *mut Participant,
+}
+
+// A retired allocation and the function that frees it.
+struct Deferred {
+    epoch: usize,
+    ptr: *mut u8,
+    free: unsafe fn(*mut u8),
+}
+
+// A pinned section. Nodes read while it is alive are not freed until it is dropped.
+pub(crate) struct Guard<'a> {
+    collector: &'a Collector,
+    participant: &'a Participant,
+}
+
+// The collector only hands out raw pointers through retire, whose caller guarantees they
+// are safe to free from any thread, and each garbage bag is only used by the pin owning it.
+unsafe impl Send for Collector {}
+unsafe impl Sync for Collector {}
+unsafe impl Sync for Participant {}
+
+impl Collector {
+    pub(crate) fn new() -> Self {
+        Collector {
+            epoch: AtomicUsize::new(0),
+            participants: AtomicPtr::new(ptr::null_mut()),
+        }
+    }
+
+    // Pins the current thread. Nodes it reads from now on stay allocated until the guard is dropped.
+    pub(crate) fn pin(&self) -> Guard<'_> {
+        let participant = self.claim();
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        participant.epoch.store(epoch << 1 | 1, Ordering::SeqCst);
+        // Make the pin visible before any shared node is read.
+        fence(Ordering::SeqCst);
+        Guard {
+            collector: self,
+            participant,
+        }
+    }
+
+    // Claims an idle participant record, or adds a new one if every record is in use.
+    fn claim(&self) -> &Participant {
+        let mut current = self.participants.load(Ordering::Acquire);
+        while let Some(participant) = unsafe { current.as_ref() } {
+            if participant
+                .in_use
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+            {
+                return participant;
+            }
+            current = participant.next;
+        }
+
+        let participant = Box::into_raw(Box::new(Participant {
+            epoch: AtomicUsize::new(0),
+            in_use: AtomicBool::new(true),
+            garbage: UnsafeCell::new(Vec::new()),
+            next: ptr::null_mut(),
+        }));
+        let mut head = self.participants.load(Ordering::Acquire);
+        loop {
+            // Nobody else can see the record until the exchange below publishes it.
+            unsafe { (*participant).next = head };
+            match self.participants.compare_exchange_weak(
+                head,
+                participant,
+                Ordering::AcqRel,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => return unsafe { &*participant },
+                Err(actual) => head = actual,
+            }
+        }
+    }
+
+    // Moves the global epoch on by one if every pinned thread has seen the current one.
+    // Returns the global epoch afterwards.
+    fn try_advance(&self) -> usize {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        fence(Ordering::SeqCst);
+
+        let mut current = self.participants.load(Ordering::Acquire);
+        while let Some(participant) = unsafe { current.as_ref() } {
+            let pinned = participant.epoch.load(Ordering::SeqCst);
+            if pinned & 1 == 1 && pinned >> 1 != epoch {
+                return epoch;
+            }
+            current = participant.next;
+        }
+
+        match self
+            .epoch
+            .compare_exchange(epoch, epoch + 1, Ordering::SeqCst, Ordering::SeqCst)
+        {
+            Ok(_) => epoch + 1,
+            Err(actual) => actual,
+        }
+    }
+}
+
+impl Drop for Collector {
+    // Nothing can be pinned once the collector is being dropped, so all garbage is freed.
+    fn drop(&mut self) {
+        let mut current = *self.participants.get_mut();
+        while !current.is_null() {
+            let participant = unsafe { Box::from_raw(current) };
+            for deferred in participant.garbage.into_inner() {
+                unsafe { (deferred.free)(deferred.ptr) };
+            }
+            current = participant.next;
+        }
+    }
+}
+
+impl Guard<'_> {
+    // Schedules a node for freeing once no pinned thread can still be reading it.
+    //
+    // Safety: ptr must come from Box::into_raw, must already be unreachable for threads
+    // that pin from now on, and must not be retired twice.
+    pub(crate) unsafe fn retire<T>(&self, ptr: *mut T) {
+        unsafe fn free<T>(ptr: *mut u8) {
+            drop(unsafe { Box::from_raw(ptr.cast::<T>()) });
+        }
+
+        let epoch = self.collector.epoch.load(Ordering::SeqCst);
+        let garbage = unsafe { &mut *self.participant.garbage.get() };
+        garbage.push(Deferred {
+            epoch,
+            ptr: ptr.cast(),
+            free: free::<T>,
+        });
+        if garbage.len() >= COLLECT_THRESHOLD {
+            self.collect();
+        }
+    }
+
+    // Tries to advance the epoch, then frees this record's garbage that is two epochs old.
+    fn collect(&self) {
+        let epoch = self.collector.try_advance();
+        let garbage = unsafe { &mut *self.participant.garbage.get() };
+        garbage.retain(|deferred| {
+            if deferred.epoch + 2 <= epoch {
+                unsafe { (deferred.free)(deferred.ptr) };
+                false
+            } else {
+                true
+            }
+        });
+    }
+}
+
+impl Drop for Guard<'_> {
+    fn drop(&mut self) {
+        if !unsafe { &*self.participant.garbage.get() }.is_empty() {
+            self.collect();
+        }
+        self.participant.epoch.store(0, Ordering::SeqCst);
+        self.participant.in_use.store(false, Ordering::Release);
+    }
+}
@@ -0,0 +1,439 @@
+use std::cell::Cell;
+use std::fmt;
+use std::marker::{PhantomData, PhantomPinned};
+use std::pin::Pin;
+use std::ptr::NonNull;
+
+// Intrusive doubly linked lists: the links live inside the values, so linking allocates nothing,
+// a value can be in as many lists as it has Link fields, and it can be unlinked in O(1) from
+// a reference to it alone.
+//
+// A list never owns its values; it borrows them, pinned, for its lifetime 'a. Pinning keeps a
+// linked value from moving, and it also promises the value's memory is not reused before its
+// destructor runs, as long as the value type is not Unpin; the Link field sees to that, and
+// intrusive_adapter! makes its caller promise not to undo it. Link's destructor unlinks it, so a
+// list can never be left pointing at a value that is gone. The list has no destructor of its own
+// that looks at its values, so values declared after the list may be dropped before it.
+
+type LinkPtr = NonNull<Link>;
+
+// The field a value embeds once for every list it can be in.
+// An unlinked Link is two empty pointers and an empty owner; it unlinks itself when dropped.
+pub struct Link {
+    prev: Cell<Option<LinkPtr>>,
+    next: Cell<Option<LinkPtr>>,
+    // The sentinel of the list this link is in, or None if it is not linked.
+    list: Cell<Option<LinkPtr>>,
+    _pin: PhantomPinned,
+}
+
+impl Link {
+    pub const fn new() -> Self {
+        Link {
+            prev: Cell::new(None),
+            next: Cell::new(None),
+            list: Cell::new(None),
+            _pin: PhantomPinned,
+        }
+    }
+
+    // Returns true if the link is in a list.
+    pub fn is_linked(&self) -> bool {
+        self.list.get().is_some()
+    }
+
+    // Links this between two adjacent links of the list with the given sentinel.
+    // Safety: prev and next must be adjacent links of that list, and this must stay put until
+    // it is unlinked.
+    unsafe fn link_between(&self, prev: LinkPtr, next: LinkPtr, list: LinkPtr) {
+        let this = NonNull::from(self);
+        self.prev.set(Some(prev));
+        self.next.set(Some(next));
+        self.list.set(Some(list));
+        unsafe {
+            prev.as_ref().next.set(Some(this));
+            next.as_ref().prev.set(Some(this));
+        }
+    }
+
+    // Joins this link's neighbours to each other and clears it. The link must be linked.
+    fn unlink(&self) {
+        let prev = self
+            .prev
+            .take()
+            .expect("linked link must have a previous link");
+        let next = self.next.take().expect("linked link must have a next link");
+        self.list.set(None);
+        // Every linked link is alive: values only leave memory through Drop, which unlinks them.
+        unsafe {
+            prev.as_ref().next.set(Some(next));
+            next.as_ref().prev.set(Some(prev));
+        }
+    }
+}
+
+impl Default for Link {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for Link {
+    fn drop(&mut self) {
+        if self.is_linked() {
+            self.unlink();
+        }
+    }
+}
+
+impl fmt::Debug for Link {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Link")
+            .field("linked", &self.is_linked())
+            .finish()
+    }
+}
+
+/// Tells a list which Link field of a value type it threads through.
+///
+/// # Safety
+///
+/// `link` and `value` must convert between a pointer to a `Value` and a pointer to one `Link`
+/// field inside it, and back. `Value` must not implement `Unpin`: lists take their values as
+/// `Pin<&Value>`, and an `Unpin` value can be pinned with the safe `Pin::new` and then moved out
+/// of its memory without being dropped, leaving its list pointing at freed memory. The
+/// `intrusive_adapter` macro writes a correct implementation.
+pub unsafe trait Adapter {
+    // The type of the values in the list.
+    type Value;
+
+    /// Returns a pointer to the Link field of the value.
+    ///
+    /// # Safety
+    ///
+    /// `value` must point to a live `Value`.
+    unsafe fn link(value: NonNull<Self::Value>) -> NonNull<Link>;
+
+    /// Returns a pointer to the value a Link field belongs to.
+    ///
+    /// # Safety
+    ///
+    /// `link` must have come from `link`.
+    unsafe fn value(link: NonNull<Link>) -> NonNull<Self::Value>;
+}
+
+/// Declares a unit struct implementing [`Adapter`] for a `Link` field of a struct.
+///
+/// ```
+/// use std::pin::pin;
+/// use payton_Assignment_2::intrusive::{IntrusiveList, Link};
+///
+/// struct Task {
+///     id: u32,
+///     owner_link: Link,
+/// }
+///
+/// // Task never implements Unpin, so a pinned Task is dropped before its memory is reused.
+/// payton_Assignment_2::intrusive_adapter!(unsafe ByOwner = Task { owner_link });
+///
+/// let task = pin!(Task { id: 1, owner_link: Link::new() });
+/// let mut list: IntrusiveList<ByOwner> = IntrusiveList::new();
+/// list.push_back(task.as_ref());
+/// assert_eq!(list.front().map(|task| task.id), Some(1));
+/// ```
+///
+/// # Safety
+///
+/// The value type must not implement `Unpin`, and nothing may move a value or reuse its memory
+/// while it is linked without dropping it first. The `Link` field already makes the type
+/// `!Unpin`; writing `unsafe` is the promise that it is never opted back in. The macro also
+/// refuses a type that implements `Unpin`, as a backstop:
+///
+/// ```compile_fail
+/// use payton_Assignment_2::intrusive::Link;
+///
+/// struct Task {
+///     link: Link,
+/// }
+///
+/// impl Unpin for Task {}
+///
+/// payton_Assignment_2::intrusive_adapter!(unsafe ByLink = Task { link });
+/// ```
+///
+/// Leaving out `unsafe` does not compile either:
+///
+/// ```compile_fail
+/// use payton_Assignment_2::intrusive::Link;
+///
+/// struct Task {
+///     link: Link,
+/// }
+///
+/// payton_Assignment_2::intrusive_adapter!(ByLink = Task { link });
+/// ```
+#[macro_export]
+macro_rules! intrusive_adapter {
+    (unsafe $vis:vis $name:ident = $value:ty { $field:ident }) => {
+        $vis struct $name;
+
+        // Only compiles if the value type does not implement Unpin: with an Unpin impl both
+        // impls of the trait apply and the type parameter cannot be inferred.
+        const _: () = {
+            trait AmbiguousIfUnpin<A> {
+                fn check() {}
+            }
+            impl<T: ?Sized> AmbiguousIfUnpin<()> for T {}
+            struct IsUnpin;
+            impl<T: ?Sized + ::std::marker::Unpin> AmbiguousIfUnpin<IsUnpin> for T {}
+            let _ = <$value as AmbiguousIfUnpin<_>>::check;
+        };
+
+        unsafe impl $crate::intrusive::Adapter for $name {
+            type Value = $value;
+
+            unsafe fn link(
+                value: ::std::ptr::NonNull<$value>,
+            ) -> ::std::ptr::NonNull<$crate::intrusive::Link> {
+                // Only compiles if the field is a Link.
+                let _: fn(&$value) -> &$crate::intrusive::Link = |value| &value.$field;
+                unsafe { value.byte_add(::std::mem::offset_of!($value, $field)).cast() }
+            }
+
+            unsafe fn value(
+                link: ::std::ptr::NonNull<$crate::intrusive::Link>,
+            ) -> ::std::ptr::NonNull<$value> {
+                unsafe { link.byte_sub(::std::mem::offset_of!($value, $field)).cast() }
+            }
+        }
+    };
+}
+
+// The heap allocated link a list's values are chained round in a ring, so a list can move
+// while values point at it. Dropping it unlinks whatever is still in the ring.
+struct Sentinel(LinkPtr);
+
+impl Sentinel {
+    fn new() -> Self {
+        let link = NonNull::from(Box::leak(Box::new(Link::new())));
+        let sentinel = unsafe { link.as_ref() };
+        sentinel.prev.set(Some(link));
+        sentinel.next.set(Some(link));
+        Sentinel(link)
+    }
+
+    fn link(&self) -> &Link {
+        unsafe { self.0.as_ref() }
+    }
+
+    fn first(&self) -> Option<LinkPtr> {
+        self.link().next.get().filter(|&link| link != self.0)
+    }
+
+    fn last(&self) -> Option<LinkPtr> {
+        self.link().prev.get().filter(|&link| link != self.0)
+    }
+}
+
+impl Drop for Sentinel {
+    fn drop(&mut self) {
+        let mut current = self.first();
+        while let Some(link) = current {
+            let link = unsafe { link.as_ref() };
+            current = link.next.take().filter(|&next| next != self.0);
+            link.prev.set(None);
+            link.list.set(None);
+        }
+        // The sentinel was never linked itself, so dropping it touches nothing else.
+        drop(unsafe { Box::from_raw(self.0.as_ptr()) });
+    }
+}
+
+// A doubly linked list of values borrowed for 'a, threaded through the Link field chosen by A.
+// Pushing and popping at either end, removing a given value and checking whether a value
+// is in this list are all O(1); len walks the list.
+// 'a: How long the values are borrowed for.
+// A: The adapter naming the value type and its Link field.
+pub struct IntrusiveList<'a, A: Adapter> {
+    sentinel: Sentinel,
+    marker: PhantomData<(A, &'a A::Value)>,
+}
+
+impl<'a, A: Adapter> IntrusiveList<'a, A> {
+    pub fn new() -> Self {
+        IntrusiveList {
+            sentinel: Sentinel::new(),
+            marker: PhantomData,
+        }
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.sentinel.first().is_none()
+    }
+
+    // Returns the number of elements in the list. O(n).
+    pub fn len(&self) -> usize {
+        self.iter().count()
+    }
+
+    // Returns a reference to the first element, or None if the list is empty.
+    pub fn front(&self) -> Option<&'a A::Value> {
+        self.sentinel.first().map(Self::value)
+    }
+
+    // Returns a reference to the last element, or None if the list is empty.
+    pub fn back(&self) -> Option<&'a A::Value> {
+        self.sentinel.last().map(Self::value)
+    }
+
+    // Links value in at the head.
+    // Panics if the value's link for this adapter is already in a list.
+    // value: Pin<&'a A::Value>: The value to link.
+    pub fn push_front(&mut self, value: Pin<&'a A::Value>) {
+        let head = self
+            .sentinel
+            .link()
+            .next
+            .get()
+            .expect("sentinel is always linked");
+        self.link_between(value, self.sentinel.0, head);
+    }
+
+    // Links value in at the tail.
+    // Panics if the value's link for this adapter is already in a list.
+    // value: Pin<&'a A::Value>: The value to link.
+    pub fn push_back(&mut self, value: Pin<&'a A::Value>) {
+        let tail = self
+            .sentinel
+            .link()
+            .prev
+            .get()
+            .expect("sentinel is always linked");
+        self.link_between(value, tail, self.sentinel.0);
+    }
+
+    // Unlinks the first element and returns it, or None if the list is empty.
+    pub fn pop_front(&mut self) -> Option<Pin<&'a A::Value>> {
+        self.sentinel.first().map(Self::take)
+    }
+
+    // Unlinks the last element and returns it, or None if the list is empty.
+    pub fn pop_back(&mut self) -> Option<Pin<&'a A::Value>> {
+        self.sentinel.last().map(Self::take)
+    }
+
+    // Returns true if value is in this list, without walking it.
+    // value: &A::Value: The value to look for.
+    pub fn contains(&self, value: &A::Value) -> bool {
+        Self::link(value).list.get() == Some(self.sentinel.0)
+    }
+
+    // Unlinks value from this list in O(1).
+    // Returns true if it was in this list, false otherwise.
+    // value: &A::Value: The value to unlink.
+    pub fn remove(&mut self, value: &A::Value) -> bool {
+        let found = self.contains(value);
+        if found {
+            Self::link(value).unlink();
+        }
+        found
+    }
+
+    // Unlinks every element.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    // Returns an iterator over references to the values, from head to tail.
+    // Call rev on it to go from tail to head.
+    pub fn iter(&self) -> Iter<'_, A> {
+        Iter {
+            front: self.sentinel.first(),
+            back: self.sentinel.last(),
+            marker: PhantomData,
+        }
+    }
+
+    fn link(value: &A::Value) -> &Link {
+        unsafe { A::link(NonNull::from(value)).as_ref() }
+    }
+
+    // Returns the value a link in this list belongs to. Values in the list are borrowed for 'a.
+    fn value(link: LinkPtr) -> &'a A::Value {
+        unsafe { A::value(link).as_ref() }
+    }
+
+    fn link_between(&mut self, value: Pin<&'a A::Value>, prev: LinkPtr, next: LinkPtr) {
+        let link = Self::link(value.get_ref());
+        assert!(!link.is_linked(), "value is already in a list");
+        // The value is pinned, so the link stays put until its destructor unlinks it.
+        unsafe { link.link_between(prev, next, self.sentinel.0) };
+    }
+
+    fn take(link: LinkPtr) -> Pin<&'a A::Value> {
+        unsafe { link.as_ref() }.unlink();
+        // The value was pinned when it was pushed.
+        unsafe { Pin::new_unchecked(Self::value(link)) }
+    }
+}
+
+impl<A: Adapter> Default for IntrusiveList<'_, A> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<A: Adapter> fmt::Debug for IntrusiveList<'_, A>
+where
+    A::Value: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Iterator over references to the values in an IntrusiveList, from either end.
+pub struct Iter<'s, A: Adapter> {
+    // The next links to yield from each end; both None once the ends have met.
+    front: Option<LinkPtr>,
+    back: Option<LinkPtr>,
+    marker: PhantomData<&'s A::Value>,
+}
+
+impl<'s, A: Adapter> Iterator for Iter<'s, A> {
+    type Item = &'s A::Value;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let link = self.front?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.front = unsafe { link.as_ref() }.next.get();
+        }
+        Some(unsafe { A::value(link).as_ref() })
+    }
+}
+
+impl<'s, A: Adapter> DoubleEndedIterator for Iter<'s, A> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let link = self.back?;
+        if self.front == self.back {
+            self.front = None;
+            self.back = None;
+        } else {
+            self.back = unsafe { link.as_ref() }.prev.get();
+        }
+        Some(unsafe { A::value(link).as_ref() })
+    }
+}
+
+impl<'a, 's, A: Adapter> IntoIterator for &'s IntrusiveList<'a, A> {
+    type Item = &'s A::Value;
+    type IntoIter = Iter<'s, A>;
+
+    fn into_iter(self) -> Iter<'s, A> {
+        self.iter()
+    }
+}
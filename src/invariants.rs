@@ -0,0 +1,102 @@
+// Structural invariants of the list types and the error reported when one is broken.
+// StaticLinkedList::validate and DynamicLinkedList::validate return the first violation
+// they find. With the debug-invariants feature every mutating method validates the list
+// afterwards and panics on a violation, so corruption shows up where it happens.
+
+use std::fmt;
+
+// Which chain of a list a violation was found on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Chain {
+    // The chain of elements starting at head.
+    Live,
+    // The chain of unused slots starting at free (StaticLinkedList only).
+    Free,
+}
+
+impl fmt::Display for Chain {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Chain::Live => write!(f, "live"),
+            Chain::Free => write!(f, "free"),
+        }
+    }
+}
+
+// A broken list invariant. Slots are slot indices for a StaticLinkedList and
+// positions counted from the head for a DynamicLinkedList.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InvariantViolation {
+    // A chain links to an index past the end of the slot array.
+    IndexOutOfRange {
+        chain: Chain,
+        index: usize,
+    },
+    // A chain comes back to a slot it already passed through.
+    Cycle {
+        chain: Chain,
+        slot: usize,
+    },
+    // A slot is on both the live chain and the free chain.
+    SharedSlot {
+        slot: usize,
+    },
+    // A slot on the live chain holds no data.
+    EmptyLiveSlot {
+        slot: usize,
+    },
+    // A slot on the free chain still holds data.
+    OccupiedFreeSlot {
+        slot: usize,
+    },
+    // A slot is on neither chain, so it can never be used again.
+    OrphanedSlot {
+        slot: usize,
+    },
+    // The cached length does not match the number of elements on the live chain.
+    LengthMismatch {
+        cached: usize,
+        actual: usize,
+    },
+    // The tail does not point at the last element. None means no element.
+    TailMismatch {
+        tail: Option<usize>,
+        last: Option<usize>,
+    },
+}
+
+impl fmt::Display for InvariantViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InvariantViolation::IndexOutOfRange { chain, index } => {
+                write!(f, "{chain} chain links to out of range index {index}")
+            }
+            InvariantViolation::Cycle { chain, slot } => {
+                write!(f, "{chain} chain loops back to slot {slot}")
+            }
+            InvariantViolation::SharedSlot { slot } => {
+                write!(f, "slot {slot} is on both the live and the free chain")
+            }
+            InvariantViolation::EmptyLiveSlot { slot } => {
+                write!(f, "live slot {slot} holds no data")
+            }
+            InvariantViolation::OccupiedFreeSlot { slot } => {
+                write!(f, "free slot {slot} still holds data")
+            }
+            InvariantViolation::OrphanedSlot { slot } => {
+                write!(f, "slot {slot} is on neither chain")
+            }
+            InvariantViolation::LengthMismatch { cached, actual } => {
+                write!(
+                    f,
+                    "cached length {cached} but the live chain has {actual} element(s)"
+                )
+            }
+            InvariantViolation::TailMismatch { tail, last } => {
+                write!(f, "tail is {tail:?} but the last element is {last:?}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for InvariantViolation {}
@@ -1,4 +1,26 @@
+pub mod adapters;
+pub mod atomic_pool;
+pub mod circular_list;
+pub mod concurrent_list;
+pub mod dot;
 pub mod dynamic_list;
+pub mod intrusive;
+pub mod invariants;
+pub mod lru_cache;
+pub mod persistent_list;
+pub mod repl;
+#[cfg(feature = "serde")]
+pub mod serde_impls;
+pub mod skip_list;
+pub mod snapshot;
+pub mod sorted_list;
 pub mod static_list;
+pub mod storage;
+pub mod sync_list;
+pub mod undo_list;
+pub mod unique_list;
+pub mod unrolled_list;
+pub mod xor_list;
 
+mod epoch;
 mod tests;
@@ -0,0 +1,355 @@
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+
+use crate::static_list::StaticNode;
+use crate::storage::SlotStorage;
+
+// A slot of an LRU cache: StaticLinkedList's node, holding an entry.
+pub type LruSlot<K, V> = StaticNode<LruEntry<K, V>>;
+
+// A cached key and value, with the link to the next more recently used entry.
+// The link to the next less recently used entry is the slot's own next.
+pub struct LruEntry<K, V> {
+    key: K,
+    value: V,
+    prev: Option<usize>,
+}
+
+// A cache that holds a fixed number of entries and evicts the least recently used one
+// when a new key is put into a full cache.
+// Entries live in a slot array doubly linked from the most recently used (head) to the least
+// recently used (tail), with a HashMap from each key to its slot, so every operation is O(1).
+// Unused slots are kept on a free list by the same machinery StaticLinkedList uses.
+// K: The key type.
+// V: The value type.
+// S: The slot storage: a fixed size array for LruCache, a Vec for HeapLruCache.
+pub struct Lru<K, V, S> {
+    slots: S,
+    map: HashMap<K, usize>,
+    head: Option<usize>,
+    tail: Option<usize>,
+    free: Option<usize>,
+    // Send so the cache can still move between threads with a callback set.
+    on_evict: Option<Box<dyn FnMut(K, V) + Send>>,
+}
+
+// An LRU cache with room for N entries, stored inline.
+pub type LruCache<K, V, const N: usize> = Lru<K, V, [LruSlot<K, V>; N]>;
+
+// An LRU cache whose slots live on the heap, so its capacity can be changed with resize.
+pub type HeapLruCache<K, V> = Lru<K, V, Vec<LruSlot<K, V>>>;
+
+impl<K, V, const N: usize> Lru<K, V, [LruSlot<K, V>; N]> {
+    pub fn new() -> Self {
+        Lru::from_slots(SlotStorage::vacant())
+    }
+}
+
+impl<K, V, const N: usize> Default for Lru<K, V, [LruSlot<K, V>; N]> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K, V> Lru<K, V, Vec<LruSlot<K, V>>> {
+    // Creates an empty cache with room for capacity entries.
+    // capacity: usize: The number of entries the cache holds before it starts evicting.
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut cache = Lru::from_slots(SlotStorage::vacant());
+        cache.add_slots(capacity);
+        cache
+    }
+
+    // Grows the storage by count unused slots, all added to the free list.
+    fn add_slots(&mut self, count: usize) {
+        for _ in 0..count {
+            self.slots.grow(&mut self.free);
+        }
+    }
+}
+
+impl<K: Hash + Eq + Clone, V> Lru<K, V, Vec<LruSlot<K, V>>> {
+    // Changes the number of entries the cache can hold. When shrinking, the least recently used
+    // entries that no longer fit are evicted, through the eviction callback if one is set.
+    // The remaining entries keep their order. O(n).
+    // capacity: usize: The new capacity.
+    pub fn resize(&mut self, capacity: usize) {
+        while self.len() > capacity {
+            self.evict();
+        }
+        // Take the entries out least recently used first and put them back in front, so the
+        // rebuilt slots are packed together in the new storage in the same order.
+        let mut entries = Vec::with_capacity(self.len());
+        while let Some(entry) = self.pop_lru() {
+            entries.push(entry);
+        }
+        self.slots = SlotStorage::vacant();
+        self.free = None;
+        self.add_slots(capacity);
+        for (key, value) in entries {
+            self.push_front(key, value);
+        }
+    }
+}
+
+impl<K, V, S: SlotStorage<LruEntry<K, V>>> Lru<K, V, S> {
+    // Creates an empty cache over storage whose slots, if it has any, are chained from slot 0.
+    fn from_slots(slots: S) -> Self {
+        let free = if slots.slots().is_empty() {
+            None
+        } else {
+            Some(0)
+        };
+        Lru {
+            slots,
+            map: HashMap::new(),
+            head: None,
+            tail: None,
+            free,
+            on_evict: None,
+        }
+    }
+
+    // Returns the maximum number of entries the cache holds.
+    pub fn capacity(&self) -> usize {
+        self.slots.slots().len()
+    }
+
+    // Returns the number of entries in the cache.
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    // Returns true if the cache has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    // Returns true if every slot is in use, so the next new key evicts an entry.
+    pub fn is_full(&self) -> bool {
+        self.free.is_none()
+    }
+
+    // Sets a function called with every entry the cache evicts to make room, replacing any
+    // set before. Entries removed with pop_lru or remove are handed back instead.
+    // on_evict: FnMut(K, V): Called with the key and value of each evicted entry.
+    pub fn set_eviction_callback(&mut self, on_evict: impl FnMut(K, V) + Send + 'static) {
+        self.on_evict = Some(Box::new(on_evict));
+    }
+
+    // Returns an iterator over the entries, from the most recently used to the least.
+    // Iterating does not count as using the entries.
+    pub fn iter(&self) -> Iter<'_, K, V> {
+        Iter {
+            slots: self.slots.slots(),
+            current: self.head,
+            remaining: self.len(),
+        }
+    }
+
+    fn entry(&self, index: usize) -> &LruEntry<K, V> {
+        self.slots.slots()[index]
+            .data
+            .as_ref()
+            .expect("linked slot must be occupied")
+    }
+}
+
+impl<K: Hash + Eq + Clone, V, S: SlotStorage<LruEntry<K, V>>> Lru<K, V, S> {
+    // Returns true if the key is in the cache, without marking it as used.
+    // key: &Q: The key to look for.
+    pub fn contains_key<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+    {
+        self.map.contains_key(key)
+    }
+
+    // Returns a reference to the value for key and marks it as the most recently used,
+    // or None if the key is not in the cache.
+    // key: &Q: The key to look up.
+    pub fn get<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = *self.map.get(key)?;
+        self.promote(index);
+        Some(&self.entry(index).value)
+    }
+
+    // Returns a mutable reference to the value for key and marks it as the most recently used,
+    // or None if the key is not in the cache.
+    // key: &Q: The key to look up.
+    pub fn get_mut<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = *self.map.get(key)?;
+        self.promote(index);
+        Some(&mut self.entry_mut(index).value)
+    }
+
+    // Returns a reference to the value for key without marking it as used,
+    // or None if the key is not in the cache.
+    // key: &Q: The key to look up.
+    pub fn peek<Q: Hash + Eq + ?Sized>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = *self.map.get(key)?;
+        Some(&self.entry(index).value)
+    }
+
+    // Returns the least recently used entry without marking it as used, or None if the cache is empty.
+    pub fn peek_lru(&self) -> Option<(&K, &V)> {
+        let entry = self.entry(self.tail?);
+        Some((&entry.key, &entry.value))
+    }
+
+    // Stores value under key and marks it as the most recently used.
+    // If the key is new and the cache is full, the least recently used entry is evicted first.
+    // A cache with no capacity evicts the new entry straight away.
+    // Returns the previous value if the key was already in the cache.
+    // key: K: The key to store the value under.
+    // value: V: The value to store.
+    pub fn put(&mut self, key: K, value: V) -> Option<V> {
+        if let Some(&index) = self.map.get(&key) {
+            self.promote(index);
+            return Some(std::mem::replace(&mut self.entry_mut(index).value, value));
+        }
+        if self.capacity() == 0 {
+            if let Some(on_evict) = &mut self.on_evict {
+                on_evict(key, value);
+            }
+            return None;
+        }
+        if self.is_full() {
+            self.evict();
+        }
+        self.push_front(key, value);
+        None
+    }
+
+    // Removes the entry for key and returns its value, or None if the key is not in the cache.
+    // key: &Q: The key to remove.
+    pub fn remove<Q: Hash + Eq + ?Sized>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+    {
+        let index = self.map.remove(key)?;
+        self.unlink(index);
+        Some(StaticNode::release(self.slots.slots_mut(), &mut self.free, index).value)
+    }
+
+    // Removes the least recently used entry and returns it, or None if the cache is empty.
+    // The eviction callback is not called.
+    pub fn pop_lru(&mut self) -> Option<(K, V)> {
+        let index = self.tail?;
+        self.unlink(index);
+        let entry = StaticNode::release(self.slots.slots_mut(), &mut self.free, index);
+        self.map.remove(&entry.key);
+        Some((entry.key, entry.value))
+    }
+
+    // Removes every entry without calling the eviction callback.
+    pub fn clear(&mut self) {
+        while self.pop_lru().is_some() {}
+    }
+
+    // Evicts the least recently used entry through the eviction callback.
+    fn evict(&mut self) {
+        if let Some((key, value)) = self.pop_lru()
+            && let Some(on_evict) = &mut self.on_evict
+        {
+            on_evict(key, value);
+        }
+    }
+
+    // Stores a new entry in a free slot, which must exist, and links it at the head.
+    fn push_front(&mut self, key: K, value: V) {
+        let entry = LruEntry {
+            key: key.clone(),
+            value,
+            prev: None,
+        };
+        let Ok(index) = StaticNode::alloc(self.slots.slots_mut(), &mut self.free, entry) else {
+            unreachable!("push_front needs a free slot");
+        };
+        self.map.insert(key, index);
+        self.link_front(index);
+    }
+
+    // Moves a linked slot to the head.
+    fn promote(&mut self, index: usize) {
+        if self.head != Some(index) {
+            self.unlink(index);
+            self.link_front(index);
+        }
+    }
+
+    // Links an occupied, unlinked slot at the head.
+    fn link_front(&mut self, index: usize) {
+        let next = self.head.replace(index);
+        self.slots.slots_mut()[index].next = next;
+        self.entry_mut(index).prev = None;
+        match next {
+            Some(next) => self.entry_mut(next).prev = Some(index),
+            None => self.tail = Some(index),
+        }
+    }
+
+    // Unlinks an occupied slot from the recency chain, leaving its data in place.
+    fn unlink(&mut self, index: usize) {
+        let prev = self.entry(index).prev;
+        let next = self.slots.slots()[index].next;
+        match prev {
+            Some(prev) => self.slots.slots_mut()[prev].next = next,
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.entry_mut(next).prev = prev,
+            None => self.tail = prev,
+        }
+    }
+
+    fn entry_mut(&mut self, index: usize) -> &mut LruEntry<K, V> {
+        self.slots.slots_mut()[index]
+            .data
+            .as_mut()
+            .expect("linked slot must be occupied")
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, S: SlotStorage<LruEntry<K, V>>> fmt::Debug
+    for Lru<K, V, S>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+pub struct Iter<'a, K, V> {
+    slots: &'a [LruSlot<K, V>],
+    current: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+    type Item = (&'a K, &'a V);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let slot = &self.slots[self.current?];
+        let entry = slot.data.as_ref()?;
+        self.current = slot.next;
+        self.remaining -= 1;
+        Some((&entry.key, &entry.value))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<K, V> ExactSizeIterator for Iter<'_, K, V> {}
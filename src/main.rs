@@ -1,3 +1,148 @@
-fn main() {
-    println!("Hello, world!");
+#![allow(non_snake_case)]
+
+// An interactive shell for trying out the list operations.
+//
+//   cargo run                              asks which storage to use
+//   cargo run -- dynamic                   starts with a DynamicLinkedList
+//   cargo run -- static 8                  starts with a StaticLinkedList of 8 slots
+//   cargo run -- --script ops.txt          runs the commands in ops.txt instead
+//   cargo run -- static 4 --trace          prints the list, and the slot layout, after every command
+//
+// With --script the exit code is 0 if every expect and validate line passed,
+// 1 if any failed, and 2 if the script could not be read or parsed.
+
+use std::io::{self, BufRead, Write};
+use std::process::ExitCode;
+
+use payton_Assignment_2::repl::{Command, HELP, Storage, new_session, run_script};
+
+struct Options {
+    storage: Option<Storage>,
+    script: Option<String>,
+    trace: bool,
+}
+
+fn parse_args() -> Result<Options, String> {
+    let mut options = Options {
+        storage: None,
+        script: None,
+        trace: false,
+    };
+    let mut storage_words = Vec::new();
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--script" => {
+                options.script = Some(args.next().ok_or("--script needs a file name")?);
+            }
+            "--trace" => options.trace = true,
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => storage_words.push(arg),
+        }
+    }
+    if !storage_words.is_empty() {
+        let storage = Storage::parse(&storage_words.join(" ")).map_err(|err| err.to_string())?;
+        options.storage = Some(storage);
+    }
+    Ok(options)
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args() {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::from(2);
+        }
+    };
+
+    match &options.script {
+        Some(path) => script(path, &options),
+        None => interactive(&options),
+    }
+}
+
+fn script(path: &str, options: &Options) -> ExitCode {
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            eprintln!("error: cannot read {path}: {err}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let storage = options.storage.unwrap_or(Storage::Dynamic);
+    let mut stdout = io::stdout().lock();
+    match run_script(&source, storage, options.trace, &mut stdout) {
+        Ok(summary) if summary.failures == 0 => {
+            println!("{} command(s), all checks passed", summary.commands);
+            ExitCode::SUCCESS
+        }
+        Ok(summary) => {
+            println!(
+                "{} command(s), {} failed check(s)",
+                summary.commands, summary.failures
+            );
+            ExitCode::FAILURE
+        }
+        Err(err) => {
+            eprintln!("error: {path}: {err}");
+            ExitCode::from(2)
+        }
+    }
+}
+
+fn interactive(options: &Options) -> ExitCode {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+
+    let storage = match options.storage {
+        Some(storage) => storage,
+        None => match choose_storage(&mut lines) {
+            Some(storage) => storage,
+            None => return ExitCode::SUCCESS,
+        },
+    };
+
+    let mut session = new_session(storage);
+    println!("type `help` for a list of commands");
+    loop {
+        prompt("> ");
+        let Some(Ok(line)) = lines.next() else {
+            break;
+        };
+        match Command::parse(&line) {
+            Ok(None) => {}
+            Ok(Some(Command::Quit)) => break,
+            Ok(Some(Command::Help)) => println!("{HELP}"),
+            Ok(Some(command)) => {
+                match session.execute(command) {
+                    Ok(output) => println!("{output}"),
+                    Err(message) => println!("error: {message}"),
+                }
+                if options.trace {
+                    println!("{}", session.trace());
+                }
+            }
+            Err(err) => println!("error: {err}"),
+        }
+    }
+    ExitCode::SUCCESS
+}
+
+// Asks until the user picks a valid storage. Returns None if input ends first.
+fn choose_storage(lines: &mut impl Iterator<Item = io::Result<String>>) -> Option<Storage> {
+    loop {
+        prompt("storage (dynamic | static <N>): ");
+        let line = lines.next()?.ok()?;
+        match Storage::parse(&line) {
+            Ok(storage) => return Some(storage),
+            Err(err) => println!("error: {err}"),
+        }
+    }
+}
+
+fn prompt(text: &str) {
+    print!("{text}");
+    let _ = io::stdout().flush();
 }
@@ -0,0 +1,222 @@
+use std::fmt;
+use std::sync::Arc;
+
+// An immutable singly linked list whose versions share structure.
+// Operations never change a list; they return a new one that reuses every node after
+// the point of change, so keeping old versions around costs only the nodes that differ.
+// Nodes are reference counted with Arc, so lists can be cloned in O(1) and sent between threads.
+pub struct PersistentList<T> {
+    head: Link<T>,
+    len: usize,
+}
+
+type Link<T> = Option<Arc<Node<T>>>;
+
+struct Node<T> {
+    data: T,
+    next: Link<T>,
+}
+
+impl<T> PersistentList<T> {
+    pub fn new() -> Self {
+        PersistentList { head: None, len: 0 }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns a reference to the first element, or None if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head.as_deref().map(|node| &node.data)
+    }
+
+    // Returns a reference to the element at index, or None if it is out of bounds.
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    // Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            next: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+
+    // Returns a new list with data in front of this one. The whole of this list is shared. O(1).
+    // data: T: The element to put at the front.
+    pub fn push_front(&self, data: T) -> Self {
+        PersistentList {
+            head: Some(Arc::new(Node {
+                data,
+                next: self.head.clone(),
+            })),
+            len: self.len + 1,
+        }
+    }
+
+    // Returns the list without its first element, sharing every node, or None if the list is empty. O(1).
+    pub fn tail(&self) -> Option<Self> {
+        let node = self.head.as_deref()?;
+        Some(PersistentList {
+            head: node.next.clone(),
+            len: self.len - 1,
+        })
+    }
+
+    // Returns true if both lists start at the same node, so they are the same version
+    // or one was cloned from the other. Lists with equal elements built separately are not.
+    // other: &PersistentList<T>: The list to compare with.
+    pub fn ptr_eq(&self, other: &Self) -> bool {
+        match (&self.head, &other.head) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            (None, None) => true,
+            _ => false,
+        }
+    }
+
+    // Returns the list starting at index, sharing its nodes.
+    fn suffix(&self, index: usize) -> Self {
+        let mut head = &self.head;
+        for _ in 0..index {
+            head = &head.as_ref().unwrap().next;
+        }
+        PersistentList {
+            head: head.clone(),
+            len: self.len - index,
+        }
+    }
+}
+
+impl<T: Clone> PersistentList<T> {
+    // Returns a new list with data inserted so that it ends up at index.
+    // The elements before index are copied; everything from index onwards is shared.
+    // Returns None if index is greater than the length of the list.
+    // index: usize: The position the new element will occupy.
+    // data: T: The element to insert.
+    pub fn insert_at_index(&self, index: usize, data: T) -> Option<Self> {
+        if index > self.len {
+            return None;
+        }
+        let list = self.suffix(index).push_front(data);
+        Some(self.with_prefix(index, list))
+    }
+
+    // Returns a new list with the element at index replaced by data.
+    // The elements before index are copied; everything after it is shared.
+    // Returns None if index is out of bounds.
+    // index: usize: The position of the element to replace.
+    // data: T: The new element.
+    pub fn update_element_at_index(&self, index: usize, data: T) -> Option<Self> {
+        if index >= self.len {
+            return None;
+        }
+        let list = self.suffix(index + 1).push_front(data);
+        Some(self.with_prefix(index, list))
+    }
+
+    // Copies the first count elements of this list in front of rest.
+    fn with_prefix(&self, count: usize, rest: Self) -> Self {
+        let prefix: Vec<&T> = self.iter().take(count).collect();
+        prefix
+            .into_iter()
+            .rev()
+            .fold(rest, |list, data| list.push_front(data.clone()))
+    }
+}
+
+// Clones share every node: only the reference count of the head changes. O(1).
+impl<T> Clone for PersistentList<T> {
+    fn clone(&self) -> Self {
+        PersistentList {
+            head: self.head.clone(),
+            len: self.len,
+        }
+    }
+}
+
+// Frees the nodes this list owns alone one at a time, stopping at the first node another
+// list still shares, so dropping a long list does not recurse through the chain.
+// Arc::into_inner hands a shared node to exactly one of the lists dropping it, even when they
+// are dropped on different threads at once, so the last owner always carries on the walk.
+impl<T> Drop for PersistentList<T> {
+    fn drop(&mut self) {
+        let mut link = self.head.take();
+        while let Some(node) = link {
+            match Arc::into_inner(node) {
+                Some(mut node) => link = node.next.take(),
+                None => break,
+            }
+        }
+    }
+}
+
+impl<T> Default for PersistentList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for PersistentList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for PersistentList<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq> Eq for PersistentList<T> {}
+
+// Builds a list holding the elements in iteration order.
+impl<T> FromIterator<T> for PersistentList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let items: Vec<T> = iter.into_iter().collect();
+        items
+            .into_iter()
+            .rev()
+            .fold(PersistentList::new(), |list, data| list.push_front(data))
+    }
+}
+
+pub struct Iter<'a, T> {
+    next: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = self.next?;
+        self.next = node.next.as_deref();
+        self.remaining -= 1;
+        Some(&node.data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a PersistentList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
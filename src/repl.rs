@@ -0,0 +1,637 @@
+// Commands for the interactive list shell in main.rs. Lines are parsed into a Command and run
+// against a Session, which wraps a DynamicLinkedList or a StaticLinkedList of i64 values.
+// run_script runs a whole file of commands, with `expect` lines as assertions.
+
+use std::fmt;
+use std::io::{self, Write};
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::invariants::InvariantViolation;
+use crate::static_list::StaticLinkedList;
+
+// The largest capacity `static <N>` accepts. Every capacity is its own list type,
+// so only this many are compiled in.
+pub const MAX_STATIC_CAPACITY: usize = 64;
+
+pub const HELP: &str = "\
+commands:
+  insert <value>            append a value
+  insert_at <index> <value> insert a value so it ends up at index
+  delete <value>            delete the first occurrence of a value
+  delete_at <index>         delete the value at index
+  update <old> <new>        replace the first occurrence of old with new
+  update_at <index> <value> replace the value at index
+  find <value>              report whether a value is in the list
+  get <index>               print the value at index
+  print                     print the whole list
+  dot                       print the list as a Graphviz DOT graph
+  validate                  check the list's structural invariants
+  expect get <index> == <value | none>
+  expect find <value> == <true | false>
+  expect len == <length>
+  expect list == [<value>, ...]
+                            check the list, reporting what was found if it differs
+  help                      show this message
+  quit                      leave the shell";
+
+// Which list a session uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Storage {
+    Dynamic,
+    Static(usize),
+}
+
+impl Storage {
+    // Parses `dynamic` or `static <N>`.
+    // line: &str: The storage choice as typed by the user.
+    pub fn parse(line: &str) -> Result<Storage, ParseError> {
+        let mut words = line.split_whitespace();
+        let storage = match words.next() {
+            Some("dynamic") => Storage::Dynamic,
+            Some("static") => {
+                let capacity = number(words.next(), "static", "capacity")?;
+                if capacity == 0 || capacity > MAX_STATIC_CAPACITY {
+                    return Err(ParseError::CapacityOutOfRange(capacity));
+                }
+                Storage::Static(capacity)
+            }
+            Some(other) => return Err(ParseError::UnknownStorage(other.to_string())),
+            None => return Err(ParseError::UnknownStorage(String::new())),
+        };
+        match words.next() {
+            Some(_) => Err(ParseError::TooManyArguments(line.trim().to_string())),
+            None => Ok(storage),
+        }
+    }
+}
+
+// One line of input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Insert(i64),
+    InsertAt(usize, i64),
+    Delete(i64),
+    DeleteAt(usize),
+    Update(i64, i64),
+    UpdateAt(usize, i64),
+    Find(i64),
+    Get(usize),
+    Print,
+    Dot,
+    Validate,
+    Expect(Expectation),
+    Help,
+    Quit,
+}
+
+// What an `expect` line says the list should look like.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expectation {
+    // get index returns the value, or None if the index is out of bounds.
+    Get(usize, Option<i64>),
+    // find value returns the bool.
+    Find(i64, bool),
+    Len(usize),
+    // The elements, head to tail.
+    List(Vec<i64>),
+}
+
+impl fmt::Display for Expectation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expectation::Get(index, Some(value)) => write!(f, "get {index} == {value}"),
+            Expectation::Get(index, None) => write!(f, "get {index} == none"),
+            Expectation::Find(value, found) => write!(f, "find {value} == {found}"),
+            Expectation::Len(len) => write!(f, "len == {len}"),
+            Expectation::List(values) => write!(f, "list == {values:?}"),
+        }
+    }
+}
+
+impl Expectation {
+    // Parses what follows `expect`. Returns None if it is not a known expectation.
+    fn parse(text: &str) -> Option<Expectation> {
+        let (query, expected) = text.split_once("==")?;
+        let query: Vec<&str> = query.split_whitespace().collect();
+        let expected = expected.trim();
+        match query.as_slice() {
+            ["get", index] => {
+                let value = match expected {
+                    "none" => None,
+                    value => Some(value.parse().ok()?),
+                };
+                Some(Expectation::Get(index.parse().ok()?, value))
+            }
+            ["find", value] => Some(Expectation::Find(
+                value.parse().ok()?,
+                expected.parse().ok()?,
+            )),
+            ["len"] => Some(Expectation::Len(expected.parse().ok()?)),
+            ["list"] => {
+                let inner = expected.strip_prefix('[')?.strip_suffix(']')?.trim();
+                let values = match inner {
+                    "" => Vec::new(),
+                    inner => inner
+                        .split(',')
+                        .map(|value| value.trim().parse().ok())
+                        .collect::<Option<Vec<i64>>>()?,
+                };
+                Some(Expectation::List(values))
+            }
+            _ => None,
+        }
+    }
+}
+
+// Why a line could not be parsed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    UnknownCommand(String),
+    UnknownStorage(String),
+    MissingArgument {
+        command: &'static str,
+        argument: &'static str,
+    },
+    InvalidNumber {
+        argument: &'static str,
+        found: String,
+    },
+    TooManyArguments(String),
+    CapacityOutOfRange(usize),
+    BadExpectation(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::UnknownCommand(command) => {
+                write!(f, "unknown command `{command}`, type `help` for a list")
+            }
+            ParseError::UnknownStorage(storage) => {
+                write!(
+                    f,
+                    "unknown storage `{storage}`, expected `dynamic` or `static <N>`"
+                )
+            }
+            ParseError::MissingArgument { command, argument } => {
+                write!(f, "`{command}` is missing its {argument} argument")
+            }
+            ParseError::InvalidNumber { argument, found } => {
+                write!(f, "{argument} must be a whole number, got `{found}`")
+            }
+            ParseError::TooManyArguments(line) => write!(f, "too many arguments in `{line}`"),
+            ParseError::CapacityOutOfRange(capacity) => write!(
+                f,
+                "capacity must be between 1 and {MAX_STATIC_CAPACITY}, got {capacity}"
+            ),
+            ParseError::BadExpectation(text) => write!(
+                f,
+                "cannot read expectation `{text}`, type `help` for the forms `expect` takes"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Parses one argument as a number, naming the command and argument if it is missing or invalid.
+fn number<N: std::str::FromStr>(
+    word: Option<&str>,
+    command: &'static str,
+    argument: &'static str,
+) -> Result<N, ParseError> {
+    let word = word.ok_or(ParseError::MissingArgument { command, argument })?;
+    word.parse().map_err(|_| ParseError::InvalidNumber {
+        argument,
+        found: word.to_string(),
+    })
+}
+
+impl Command {
+    // Parses one line. Returns Ok(None) for a blank line or a comment starting with #.
+    // line: &str: The line as typed by the user.
+    pub fn parse(line: &str) -> Result<Option<Command>, ParseError> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let mut words = line.split_whitespace();
+        let name = words.next().unwrap_or_default();
+        if name == "expect" {
+            let text = line["expect".len()..].trim();
+            return match Expectation::parse(text) {
+                Some(expectation) => Ok(Some(Command::Expect(expectation))),
+                None => Err(ParseError::BadExpectation(text.to_string())),
+            };
+        }
+        let command = match name {
+            "insert" => Command::Insert(number(words.next(), "insert", "value")?),
+            "insert_at" => Command::InsertAt(
+                number(words.next(), "insert_at", "index")?,
+                number(words.next(), "insert_at", "value")?,
+            ),
+            "delete" => Command::Delete(number(words.next(), "delete", "value")?),
+            "delete_at" => Command::DeleteAt(number(words.next(), "delete_at", "index")?),
+            "update" => Command::Update(
+                number(words.next(), "update", "old value")?,
+                number(words.next(), "update", "new value")?,
+            ),
+            "update_at" => Command::UpdateAt(
+                number(words.next(), "update_at", "index")?,
+                number(words.next(), "update_at", "value")?,
+            ),
+            "find" => Command::Find(number(words.next(), "find", "value")?),
+            "get" => Command::Get(number(words.next(), "get", "index")?),
+            "print" => Command::Print,
+            "dot" => Command::Dot,
+            "validate" => Command::Validate,
+            "help" => Command::Help,
+            "quit" | "exit" => Command::Quit,
+            _ => return Err(ParseError::UnknownCommand(name.to_string())),
+        };
+        match words.next() {
+            Some(_) => Err(ParseError::TooManyArguments(line.to_string())),
+            None => Ok(Some(command)),
+        }
+    }
+}
+
+// The list operations a session needs, implemented by both list types.
+trait ReplList {
+    fn len(&self) -> usize;
+    // Returns the number of slots, or None for a list that grows as needed.
+    fn capacity(&self) -> Option<usize>;
+    fn insert(&mut self, data: i64);
+    fn insert_at_index(&mut self, index: usize, data: i64);
+    fn delete_element(&mut self, data: i64) -> bool;
+    fn delete_at_index(&mut self, index: usize) -> bool;
+    fn update_element(&mut self, old_data: i64, new_data: i64) -> bool;
+    fn update_element_at_index(&mut self, index: usize, data: i64) -> bool;
+    fn find(&self, data: i64) -> bool;
+    fn get(&self, index: usize) -> Option<i64>;
+    fn contents(&self) -> Vec<i64>;
+    fn to_dot(&self) -> String;
+    fn validate(&self) -> Result<(), InvariantViolation>;
+    // Returns the head and free slots and every slot of a StaticLinkedList, one per line,
+    // or None for a DynamicLinkedList.
+    fn layout(&self) -> Option<String>;
+}
+
+// Forwards every ReplList method to the list method of the same name.
+macro_rules! forward_repl_list {
+    () => {
+        fn len(&self) -> usize {
+            Self::len(self)
+        }
+
+        fn insert(&mut self, data: i64) {
+            Self::insert(self, data)
+        }
+
+        fn insert_at_index(&mut self, index: usize, data: i64) {
+            Self::insert_at_index(self, index, data)
+        }
+
+        fn delete_element(&mut self, data: i64) -> bool {
+            Self::delete_element(self, data)
+        }
+
+        fn delete_at_index(&mut self, index: usize) -> bool {
+            Self::delete_at_index(self, index)
+        }
+
+        fn update_element(&mut self, old_data: i64, new_data: i64) -> bool {
+            Self::update_element(self, old_data, new_data)
+        }
+
+        fn update_element_at_index(&mut self, index: usize, data: i64) -> bool {
+            Self::update_element_at_index(self, index, data)
+        }
+
+        fn find(&self, data: i64) -> bool {
+            Self::find(self, data)
+        }
+
+        fn get(&self, index: usize) -> Option<i64> {
+            Self::get(self, index)
+        }
+
+        fn contents(&self) -> Vec<i64> {
+            self.iter().copied().collect()
+        }
+
+        fn to_dot(&self) -> String {
+            Self::to_dot(self)
+        }
+
+        fn validate(&self) -> Result<(), InvariantViolation> {
+            Self::validate(self)
+        }
+    };
+}
+
+impl ReplList for DynamicLinkedList<i64> {
+    fn capacity(&self) -> Option<usize> {
+        None
+    }
+
+    fn layout(&self) -> Option<String> {
+        None
+    }
+
+    forward_repl_list!();
+}
+
+impl<const N: usize> ReplList for StaticLinkedList<i64, N> {
+    fn capacity(&self) -> Option<usize> {
+        Some(N)
+    }
+
+    fn layout(&self) -> Option<String> {
+        let slot = |index: Option<usize>| index.map_or("-".to_string(), |i| i.to_string());
+        let mut layout = format!(
+            "head: {}  free: {}",
+            slot(self.head_slot()),
+            slot(self.free_slot())
+        );
+        for (i, (data, next)) in self.slots().enumerate() {
+            let data = data.map_or("free".to_string(), i64::to_string);
+            layout.push_str(&format!("\n  slot {i}: {data:<8} next: {}", slot(next)));
+        }
+        Some(layout)
+    }
+
+    forward_repl_list!();
+}
+
+// A list the shell runs commands against.
+pub trait Session {
+    // Runs one command. Returns the text to show the user, or an error message
+    // if the command could not be carried out (the list is then unchanged).
+    // Quit and Help are handled by the caller and return an empty string.
+    fn execute(&mut self, command: Command) -> Result<String, String>;
+
+    // Returns the list and, for a StaticLinkedList, its slot layout, as shown by --trace.
+    fn trace(&self) -> String;
+}
+
+// Creates a session with an empty list of the chosen storage.
+pub fn new_session(storage: Storage) -> Box<dyn Session> {
+    match storage {
+        Storage::Dynamic => Box::new(ListSession(DynamicLinkedList::<i64>::new())),
+        Storage::Static(capacity) => new_static_session(capacity),
+    }
+}
+
+// Maps a capacity chosen at run time to the StaticLinkedList type with that capacity.
+macro_rules! static_sessions {
+    ($capacity:expr, [$($n:literal)*]) => {
+        match $capacity {
+            $($n => Box::new(ListSession(StaticLinkedList::<i64, $n>::new())),)*
+            capacity => panic!(
+                "capacity {capacity} is outside 1..={MAX_STATIC_CAPACITY}; Storage::parse checks this"
+            ),
+        }
+    };
+}
+
+fn new_static_session(capacity: usize) -> Box<dyn Session> {
+    static_sessions!(capacity, [
+        1 2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19 20 21 22 23 24 25 26 27 28 29 30 31 32
+        33 34 35 36 37 38 39 40 41 42 43 44 45 46 47 48 49 50 51 52 53 54 55 56 57 58 59 60 61 62
+        63 64
+    ])
+}
+
+struct ListSession<L>(L);
+
+impl<L: ReplList> ListSession<L> {
+    fn out_of_bounds(&self, index: usize) -> String {
+        format!(
+            "index {index} is out of bounds for a list of length {}",
+            self.0.len()
+        )
+    }
+
+    fn print(&self) -> String {
+        let contents = format!("{:?}", self.0.contents());
+        match self.0.capacity() {
+            Some(capacity) => format!("{contents} ({} of {capacity} slots used)", self.0.len()),
+            None => contents,
+        }
+    }
+}
+
+impl<L: ReplList> Session for ListSession<L> {
+    fn execute(&mut self, command: Command) -> Result<String, String> {
+        let list = &mut self.0;
+        let is_full = list.capacity() == Some(list.len());
+        match command {
+            Command::Insert(value) => {
+                if is_full {
+                    return Err(format!("the list is full, {value} was not inserted"));
+                }
+                list.insert(value);
+                Ok(format!("inserted {value}"))
+            }
+            Command::InsertAt(index, value) => {
+                if index > list.len() {
+                    return Err(self.out_of_bounds(index));
+                }
+                if is_full {
+                    return Err(format!("the list is full, {value} was not inserted"));
+                }
+                list.insert_at_index(index, value);
+                Ok(format!("inserted {value} at index {index}"))
+            }
+            Command::Delete(value) => match list.delete_element(value) {
+                true => Ok(format!("deleted {value}")),
+                false => Err(format!("{value} is not in the list")),
+            },
+            Command::DeleteAt(index) => match list.get(index) {
+                Some(value) => {
+                    list.delete_at_index(index);
+                    Ok(format!("deleted {value} at index {index}"))
+                }
+                None => Err(self.out_of_bounds(index)),
+            },
+            Command::Update(old, new) => match list.update_element(old, new) {
+                true => Ok(format!("updated {old} to {new}")),
+                false => Err(format!("{old} is not in the list")),
+            },
+            Command::UpdateAt(index, value) => match list.update_element_at_index(index, value) {
+                true => Ok(format!("updated index {index} to {value}")),
+                false => Err(self.out_of_bounds(index)),
+            },
+            Command::Find(value) => match list.find(value) {
+                true => Ok(format!("{value} is in the list")),
+                false => Ok(format!("{value} is not in the list")),
+            },
+            Command::Get(index) => match list.get(index) {
+                Some(value) => Ok(value.to_string()),
+                None => Err(self.out_of_bounds(index)),
+            },
+            Command::Print => Ok(self.print()),
+            Command::Dot => Ok(list.to_dot().trim_end().to_string()),
+            Command::Validate => match list.validate() {
+                Ok(()) => Ok("ok".to_string()),
+                Err(violation) => Err(format!("invariant violated: {violation}")),
+            },
+            Command::Expect(expectation) => self.check(&expectation).map(|()| "ok".to_string()),
+            Command::Help | Command::Quit => Ok(String::new()),
+        }
+    }
+
+    fn trace(&self) -> String {
+        match self.0.layout() {
+            Some(layout) => format!("{}\n{layout}", self.print()),
+            None => self.print(),
+        }
+    }
+}
+
+impl<L: ReplList> ListSession<L> {
+    // Returns an error describing what was found if the expectation does not hold.
+    fn check(&self, expectation: &Expectation) -> Result<(), String> {
+        let list = &self.0;
+        let found = match expectation {
+            Expectation::Get(index, value) => {
+                let found = list.get(*index);
+                (found != *value).then(|| match found {
+                    Some(found) => found.to_string(),
+                    None => "none".to_string(),
+                })
+            }
+            Expectation::Find(value, expected) => {
+                let found = list.find(*value);
+                (found != *expected).then(|| found.to_string())
+            }
+            Expectation::Len(len) => (list.len() != *len).then(|| list.len().to_string()),
+            Expectation::List(values) => {
+                let found = list.contents();
+                (found != *values).then(|| format!("{found:?}"))
+            }
+        };
+        match found {
+            Some(found) => Err(format!("expected {expectation}, found {found}")),
+            None => Ok(()),
+        }
+    }
+}
+
+// The result of a script that ran to the end.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScriptSummary {
+    pub commands: usize,
+    // The number of expect lines that did not hold plus validate commands that found a violation.
+    pub failures: usize,
+}
+
+// Why a script could not be run to the end.
+#[derive(Debug)]
+pub enum ScriptError {
+    Io(io::Error),
+    // A line could not be parsed. line is counted from 1.
+    Parse { line: usize, error: ParseError },
+    // A `storage` line came after the first command.
+    LateStorage { line: usize },
+}
+
+impl fmt::Display for ScriptError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScriptError::Io(err) => write!(f, "script i/o failed: {err}"),
+            ScriptError::Parse { line, error } => write!(f, "line {line}: {error}"),
+            ScriptError::LateStorage { line } => {
+                write!(
+                    f,
+                    "line {line}: `storage` must come before the first command"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ScriptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ScriptError::Io(err) => Some(err),
+            ScriptError::Parse { error, .. } => Some(error),
+            ScriptError::LateStorage { .. } => None,
+        }
+    }
+}
+
+impl From<io::Error> for ScriptError {
+    fn from(err: io::Error) -> Self {
+        ScriptError::Io(err)
+    }
+}
+
+// Runs a script of commands, one per line, writing each command and its result to out.
+// The script may start with `storage dynamic` or `storage static <N>` to pick its own list;
+// otherwise storage is used. A failed expect or validate is reported and counted, and the
+// script carries on; a line that cannot be parsed stops it.
+// source: &str: The script text.
+// storage: Storage: The list to use if the script does not choose one.
+// trace: bool: Whether to print the list, and the slot layout of a static list, after every command.
+// out: &mut dyn Write: Where the transcript goes.
+pub fn run_script(
+    source: &str,
+    storage: Storage,
+    trace: bool,
+    out: &mut dyn Write,
+) -> Result<ScriptSummary, ScriptError> {
+    let mut storage = storage;
+    let mut session: Option<Box<dyn Session>> = None;
+    let mut summary = ScriptSummary {
+        commands: 0,
+        failures: 0,
+    };
+
+    for (number, line) in source.lines().enumerate() {
+        let number = number + 1;
+        if let Some(choice) = line.trim().strip_prefix("storage ") {
+            if session.is_some() {
+                return Err(ScriptError::LateStorage { line: number });
+            }
+            storage = Storage::parse(choice).map_err(|error| ScriptError::Parse {
+                line: number,
+                error,
+            })?;
+            continue;
+        }
+        let command = match Command::parse(line) {
+            Ok(Some(command)) => command,
+            Ok(None) => continue,
+            Err(error) => {
+                return Err(ScriptError::Parse {
+                    line: number,
+                    error,
+                });
+            }
+        };
+        if command == Command::Quit {
+            break;
+        }
+
+        let session = session.get_or_insert_with(|| new_session(storage));
+        summary.commands += 1;
+        writeln!(out, "> {}", line.trim())?;
+        let checks = matches!(command, Command::Expect(_) | Command::Validate);
+        match session.execute(command) {
+            Ok(output) if output.is_empty() => {}
+            Ok(output) => writeln!(out, "{output}")?,
+            Err(message) if checks => {
+                summary.failures += 1;
+                writeln!(out, "FAILED at line {number}: {message}")?;
+            }
+            Err(message) => writeln!(out, "error: {message}")?,
+        }
+        if trace {
+            writeln!(out, "{}", session.trace())?;
+        }
+    }
+    Ok(summary)
+}
@@ -0,0 +1,151 @@
+// Serialize and Deserialize implementations, enabled by the serde feature.
+// Both lists are written as plain sequences of their elements, head to tail.
+// The raw module offers a second representation for StaticLinkedList that
+// keeps the exact slot layout.
+
+use std::fmt;
+use std::marker::PhantomData;
+
+use serde::de::{Deserialize, Deserializer, Error, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::static_list::StaticLinkedList;
+
+impl<T: Serialize> Serialize for DynamicLinkedList<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for DynamicLinkedList<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for ListVisitor<T> {
+            type Value = DynamicLinkedList<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a sequence")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut list = DynamicLinkedList::new();
+                while let Some(data) = seq.next_element()? {
+                    list.push_back(data);
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor(PhantomData))
+    }
+}
+
+impl<T: Serialize, const N: usize> Serialize for StaticLinkedList<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for StaticLinkedList<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct ListVisitor<T, const N: usize>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ListVisitor<T, N> {
+            type Value = StaticLinkedList<T, N>;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "a sequence of at most {N} elements")
+            }
+
+            fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+                let mut list = StaticLinkedList::new();
+                while let Some(data) = seq.next_element()? {
+                    if list.push_back(data).is_err() {
+                        // Count the element that did not fit so the error reports the real length
+                        return Err(A::Error::invalid_length(N + 1, &self));
+                    }
+                }
+                Ok(list)
+            }
+        }
+
+        deserializer.deserialize_seq(ListVisitor::<T, N>(PhantomData))
+    }
+}
+
+// The raw representation of a StaticLinkedList: the capacity, the head and free
+// slot indices, and every slot with its data and next index. Deserializing checks
+// that the layout is a valid list before accepting it.
+// Use with #[serde(with = "payton_Assignment_2::serde_impls::raw")] on a field,
+// or call serialize and deserialize directly.
+pub mod raw {
+    use serde::de::{Deserialize, Deserializer, Error};
+    use serde::ser::{Serialize, Serializer};
+
+    use crate::static_list::StaticLinkedList;
+
+    #[derive(serde::Serialize)]
+    struct RawListRef<'a, T> {
+        capacity: usize,
+        head: Option<usize>,
+        free: Option<usize>,
+        slots: Vec<RawSlot<&'a T>>,
+    }
+
+    #[derive(serde::Deserialize)]
+    struct RawList<T> {
+        capacity: usize,
+        head: Option<usize>,
+        free: Option<usize>,
+        slots: Vec<RawSlot<T>>,
+    }
+
+    #[derive(serde::Serialize, serde::Deserialize)]
+    struct RawSlot<T> {
+        data: Option<T>,
+        next: Option<usize>,
+    }
+
+    // Writes the list with its full slot layout.
+    pub fn serialize<T: Serialize, const N: usize, S: Serializer>(
+        list: &StaticLinkedList<T, N>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        RawListRef {
+            capacity: N,
+            head: list.head_slot(),
+            free: list.free_slot(),
+            slots: list
+                .slots()
+                .map(|(data, next)| RawSlot { data, next })
+                .collect(),
+        }
+        .serialize(serializer)
+    }
+
+    // Reads a list written by serialize, restoring every slot exactly.
+    // Fails if the capacity differs from N or the layout is not a valid list.
+    pub fn deserialize<'de, T: Deserialize<'de>, const N: usize, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<StaticLinkedList<T, N>, D::Error> {
+        let raw = RawList::<T>::deserialize(deserializer)?;
+        if raw.capacity != N {
+            return Err(D::Error::custom(format_args!(
+                "capacity {} does not match the list capacity {}",
+                raw.capacity, N
+            )));
+        }
+
+        let slots: Vec<_> = raw
+            .slots
+            .into_iter()
+            .map(|slot| (slot.data, slot.next))
+            .collect();
+        let slots = <[_; N]>::try_from(slots).map_err(|slots: Vec<_>| {
+            D::Error::invalid_length(slots.len(), &format!("{N} slots").as_str())
+        })?;
+        StaticLinkedList::from_parts(slots, raw.head, raw.free).map_err(D::Error::custom)
+    }
+}
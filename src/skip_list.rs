@@ -0,0 +1,558 @@
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+// The most levels a node can have, enough for far more elements than fit in memory.
+const MAX_LEVEL: usize = 32;
+
+// The seed used by new, so lists built the same way always get the same shape.
+const DEFAULT_SEED: u64 = 0x5EED_0F5C_1B11_57ED;
+
+// SplitMix64, a small PRNG that picks node heights. Kept local to the list so every list
+// built from the same seed and the same operations has the same shape.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+// A link from a node to the next node at one level, and how many positions it skips.
+// The span is only meaningful while next is Some.
+#[derive(Clone, Copy)]
+struct SkipLink {
+    next: Option<usize>,
+    span: usize,
+}
+
+struct SkipNode<T> {
+    // None only for the head and for nodes on the free list.
+    data: Option<T>,
+    links: Vec<SkipLink>,
+}
+
+// The last node before a position at every level, and the position of each.
+// Positions count the head as 0, so the element at index i is at position i + 1.
+struct Path {
+    nodes: [usize; MAX_LEVEL],
+    ranks: [usize; MAX_LEVEL],
+}
+
+// An indexable skip list: a sequence with O(log n) expected time access by index.
+// Every node is on the bottom level and each level above holds about half the nodes of the one
+// below, so a search drops down the levels skipping most of the list. Each link records how many
+// positions it spans, which lets a search stop at an index as well as at a value.
+// Nodes live in a Vec and link to each other by index; removed nodes are reused.
+pub struct SkipList<T> {
+    // Slot 0 is the head, which has a link at every level and no data.
+    nodes: Vec<SkipNode<T>>,
+    free: Vec<usize>,
+    // The number of levels in use, at least 1.
+    level: usize,
+    len: usize,
+    rng: SplitMix64,
+}
+
+impl<T> SkipList<T> {
+    pub fn new() -> Self {
+        Self::with_seed(DEFAULT_SEED)
+    }
+
+    // Creates an empty list whose node heights come from a PRNG seeded with seed.
+    // seed: u64: The PRNG seed.
+    pub fn with_seed(seed: u64) -> Self {
+        let head = SkipNode {
+            data: None,
+            links: vec![
+                SkipLink {
+                    next: None,
+                    span: 0,
+                };
+                MAX_LEVEL
+            ],
+        };
+        SkipList {
+            nodes: vec![head],
+            free: Vec::new(),
+            level: 1,
+            len: 0,
+            rng: SplitMix64(seed),
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns an iterator over references to the elements, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            nodes: &self.nodes,
+            current: self.nodes[0].links[0].next,
+            remaining: self.len,
+        }
+    }
+
+    // Returns a reference to the element at index, or None if it is out of bounds. O(log n).
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let node = self.node_at(index)?;
+        self.nodes[node].data.as_ref()
+    }
+
+    // Returns a mutable reference to the element at index, or None if it is out of bounds. O(log n).
+    // index: usize: The position of the element.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let node = self.node_at(index)?;
+        self.nodes[node].data.as_mut()
+    }
+
+    // Inserts a new element at the specified index in O(log n).
+    // If the index is out of bounds, the element will not be inserted.
+    // index: usize: The position the new element will occupy.
+    // data: T: The element to insert.
+    pub fn insert_at_index(&mut self, index: usize, data: T) {
+        if index <= self.len {
+            let path = self.path(|position, _| position <= index);
+            self.insert_at_path(&path, data);
+        }
+    }
+
+    // Appends data at the end of the list in O(log n).
+    // data: T: The element to append.
+    pub fn push_back(&mut self, data: T) {
+        self.insert_at_index(self.len, data);
+    }
+
+    // Deletes the element at the specified index in O(log n).
+    // Returns true if the element was found and deleted, false otherwise.
+    // index: usize: The position of the element to delete.
+    pub fn delete_at_index(&mut self, index: usize) -> bool {
+        self.remove_at_index(index).is_some()
+    }
+
+    // Removes the element at the specified index and returns it, or None if it is out of bounds.
+    // index: usize: The position of the element to remove.
+    pub fn remove_at_index(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let path = self.path(|position, _| position <= index);
+        self.remove_at_path(&path)
+    }
+
+    // Replaces the element at the specified index with data in O(log n).
+    // Returns true if the index is valid and the element was updated, false otherwise.
+    // index: usize: The position of the element.
+    // data: T: The new element.
+    pub fn update_element_at_index(&mut self, index: usize, data: T) -> bool {
+        match self.get_mut(index) {
+            Some(slot) => {
+                *slot = data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        self.nodes.truncate(1);
+        self.nodes[0].links.fill(SkipLink {
+            next: None,
+            span: 0,
+        });
+        self.free.clear();
+        self.level = 1;
+        self.len = 0;
+    }
+
+    // Returns the node holding the element at index, or None if it is out of bounds.
+    fn node_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+        let target = index + 1;
+        let mut node = 0;
+        let mut position = 0;
+        for level in (0..self.level).rev() {
+            while let SkipLink {
+                next: Some(next),
+                span,
+            } = self.nodes[node].links[level]
+                && position + span <= target
+            {
+                position += span;
+                node = next;
+            }
+            if position == target {
+                return Some(node);
+            }
+        }
+        None
+    }
+
+    // Walks down from the head, moving forward at each level while before returns true for the
+    // next node, and records where the walk left each level.
+    // before: FnMut(usize, &T) -> bool: Called with the next node's position and data.
+    fn path(&self, mut before: impl FnMut(usize, &T) -> bool) -> Path {
+        let mut path = Path {
+            nodes: [0; MAX_LEVEL],
+            ranks: [0; MAX_LEVEL],
+        };
+        let mut node = 0;
+        let mut position = 0;
+        for level in (0..self.level).rev() {
+            while let SkipLink {
+                next: Some(next),
+                span,
+            } = self.nodes[node].links[level]
+                && before(position + span, self.data(next))
+            {
+                position += span;
+                node = next;
+            }
+            path.nodes[level] = node;
+            path.ranks[level] = position;
+        }
+        path
+    }
+
+    // Links a new node holding data straight after the bottom node of path.
+    fn insert_at_path(&mut self, path: &Path, data: T) {
+        let height = self.random_height();
+        // Levels above the old top are already recorded as starting at the head with rank 0.
+        self.level = self.level.max(height);
+        let position = path.ranks[0] + 1;
+
+        let mut links = Vec::with_capacity(height);
+        for level in 0..height {
+            let prev = &mut self.nodes[path.nodes[level]].links[level];
+            links.push(SkipLink {
+                next: prev.next,
+                // The old next moves up one position, so it is span + rank + 1 - position away.
+                span: if prev.next.is_some() {
+                    prev.span + path.ranks[level] + 1 - position
+                } else {
+                    0
+                },
+            });
+        }
+        let node = self.alloc(SkipNode {
+            data: Some(data),
+            links,
+        });
+        for level in 0..self.level {
+            let prev = &mut self.nodes[path.nodes[level]].links[level];
+            if level < height {
+                *prev = SkipLink {
+                    next: Some(node),
+                    span: position - path.ranks[level],
+                };
+            } else if prev.next.is_some() {
+                // This link passes over the new node.
+                prev.span += 1;
+            }
+        }
+        self.len += 1;
+    }
+
+    // Unlinks the node straight after the bottom node of path and returns its data,
+    // or None if there is no such node.
+    fn remove_at_path(&mut self, path: &Path) -> Option<T> {
+        let target = self.nodes[path.nodes[0]].links[0].next?;
+        for level in 0..self.level {
+            let removed = self.nodes[target].links.get(level).copied();
+            let prev = &mut self.nodes[path.nodes[level]].links[level];
+            match removed {
+                Some(removed) if prev.next == Some(target) => {
+                    *prev = SkipLink {
+                        next: removed.next,
+                        span: if removed.next.is_some() {
+                            prev.span + removed.span - 1
+                        } else {
+                            0
+                        },
+                    };
+                }
+                _ if prev.next.is_some() => prev.span -= 1,
+                _ => {}
+            }
+        }
+        while self.level > 1 && self.nodes[0].links[self.level - 1].next.is_none() {
+            self.level -= 1;
+        }
+        self.len -= 1;
+
+        let node = &mut self.nodes[target];
+        node.links.clear();
+        self.free.push(target);
+        node.data.take()
+    }
+
+    // Stores a node in a free slot, or a new one if there are none.
+    fn alloc(&mut self, node: SkipNode<T>) -> usize {
+        match self.free.pop() {
+            Some(index) => {
+                self.nodes[index] = node;
+                index
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        }
+    }
+
+    // Returns a height for a new node: 1 with probability 1/2, 2 with probability 1/4, and so on.
+    fn random_height(&mut self) -> usize {
+        let bits = self.rng.next() | 1 << (MAX_LEVEL - 1);
+        bits.trailing_zeros() as usize + 1
+    }
+
+    fn data(&self, index: usize) -> &T {
+        self.nodes[index]
+            .data
+            .as_ref()
+            .expect("linked node must hold data")
+    }
+}
+
+impl<T: PartialEq> SkipList<T> {
+    // Returns true if the data is in the list, false otherwise.
+    // The list is not ordered, so this is a linear scan; use SkipSet for O(log n) lookups.
+    // data: T: The element to look for.
+    pub fn find(&self, data: T) -> bool {
+        self.iter().any(|item| *item == data)
+    }
+}
+
+impl<T> Default for SkipList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for SkipList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Builds a list holding the elements in iteration order.
+impl<T> FromIterator<T> for SkipList<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = SkipList::new();
+        for data in iter {
+            list.push_back(data);
+        }
+        list
+    }
+}
+
+// A skip list used as an ordered set: elements are kept in ascending order without duplicates,
+// so lookups by value and by rank are both O(log n) expected time.
+pub struct SkipSet<T: Ord> {
+    list: SkipList<T>,
+}
+
+impl<T: Ord> SkipSet<T> {
+    pub fn new() -> Self {
+        SkipSet {
+            list: SkipList::new(),
+        }
+    }
+
+    // Creates an empty set whose node heights come from a PRNG seeded with seed.
+    // seed: u64: The PRNG seed.
+    pub fn with_seed(seed: u64) -> Self {
+        SkipSet {
+            list: SkipList::with_seed(seed),
+        }
+    }
+
+    // Returns the number of elements in the set.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // Returns true if the set has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> Iter<'_, T> {
+        self.list.iter()
+    }
+
+    // Inserts data at its place in the order unless an equal element is already present.
+    // Returns true if data was inserted, false if it was already in the set.
+    // data: T: The element to insert.
+    pub fn insert(&mut self, data: T) -> bool {
+        let path = self.list.path(|_, item| *item < data);
+        if self.next_after(&path) == Some(&data) {
+            return false;
+        }
+        self.list.insert_at_path(&path, data);
+        true
+    }
+
+    // Removes the element equal to data and returns it, or None if it is not in the set.
+    // data: &T: The element to remove.
+    pub fn remove(&mut self, data: &T) -> Option<T> {
+        let path = self.list.path(|_, item| item < data);
+        if self.next_after(&path) != Some(data) {
+            return None;
+        }
+        self.list.remove_at_path(&path)
+    }
+
+    // Returns true if an element equal to data is in the set.
+    // data: &T: The element to look for.
+    pub fn contains(&self, data: &T) -> bool {
+        self.rank(data).is_some()
+    }
+
+    // Returns the index of the element equal to data in ascending order, or None if it is not in the set.
+    // data: &T: The element to look for.
+    pub fn rank(&self, data: &T) -> Option<usize> {
+        let path = self.list.path(|_, item| item < data);
+        (self.next_after(&path) == Some(data)).then_some(path.ranks[0])
+    }
+
+    // Returns the element at index in ascending order, or None if it is out of bounds.
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.get(index)
+    }
+
+    // Deletes the element at index in ascending order.
+    // Returns true if the element was found and deleted, false otherwise.
+    // index: usize: The position of the element to delete.
+    pub fn delete_at_index(&mut self, index: usize) -> bool {
+        self.list.delete_at_index(index)
+    }
+
+    // Returns the smallest element, or None if the set is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.list.get(0)
+    }
+
+    // Returns the largest element, or None if the set is empty.
+    pub fn last(&self) -> Option<&T> {
+        self.list.get(self.len().checked_sub(1)?)
+    }
+
+    // Returns an iterator over the elements that fall within range, in ascending order.
+    // Finding the start is O(log n); the iteration stops at the first element past the end.
+    // range: R: The bounds to iterate over, for example a..b or a..=b.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T, R> {
+        let path = match range.start_bound() {
+            Bound::Included(start) => self.list.path(|_, item| item < start),
+            Bound::Excluded(start) => self.list.path(|_, item| item <= start),
+            Bound::Unbounded => self.list.path(|_, _| false),
+        };
+        Range {
+            iter: Iter {
+                nodes: &self.list.nodes,
+                current: self.list.nodes[path.nodes[0]].links[0].next,
+                remaining: self.len() - path.ranks[0],
+            },
+            range,
+        }
+    }
+
+    // Returns the element straight after the bottom node of path, if any.
+    fn next_after(&self, path: &Path) -> Option<&T> {
+        let next = self.list.nodes[path.nodes[0]].links[0].next?;
+        Some(self.list.data(next))
+    }
+}
+
+impl<T: Ord> Default for SkipSet<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord + fmt::Debug> fmt::Debug for SkipSet<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+// Builds a set from the elements, dropping duplicates.
+impl<T: Ord> FromIterator<T> for SkipSet<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut set = SkipSet::new();
+        for data in iter {
+            set.insert(data);
+        }
+        set
+    }
+}
+
+// Iterator over references to the elements of a SkipList or SkipSet, front to back.
+pub struct Iter<'a, T> {
+    nodes: &'a [SkipNode<T>],
+    current: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let node = &self.nodes[self.current?];
+        self.current = node.links[0].next;
+        self.remaining -= 1;
+        node.data.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+impl<'a, T> IntoIterator for &'a SkipList<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+// Iterator over the elements of a SkipSet that fall within a range.
+pub struct Range<'a, T, R: RangeBounds<T>> {
+    iter: Iter<'a, T>,
+    range: R,
+}
+
+impl<'a, T: Ord, R: RangeBounds<T>> Iterator for Range<'a, T, R> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.iter.next()?;
+        if self.range.contains(item) {
+            Some(item)
+        } else {
+            // Everything after the first element past the end is also past it.
+            self.iter.current = None;
+            None
+        }
+    }
+}
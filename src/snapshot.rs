@@ -0,0 +1,383 @@
+// Versioned binary snapshots of a StaticLinkedList.
+//
+// A snapshot is a fixed size header followed by one fixed size record per slot,
+// so slot i always starts at HEADER_SIZE + i * record size and the file can be
+// mapped and read in place. All integers are little endian.
+//
+// Header (32 bytes):
+//   magic         4 bytes  b"SLLS"
+//   version       u16      FORMAT_VERSION
+//   reserved      u16      0
+//   capacity      u32      N
+//   element size  u32      T::SIZE
+//   head          u32      slot index, or NO_SLOT
+//   free          u32      slot index, or NO_SLOT
+//   live count    u32      number of elements
+//   checksum      u32      CRC32 of the first 28 header bytes and every slot record
+//
+// Slot record (5 + T::SIZE bytes):
+//   next          u32      slot index, or NO_SLOT
+//   occupied      u8       1 if the slot holds data, 0 if it is free
+//   payload       T::SIZE  the encoded data, zeroed for free slots
+
+use std::fmt;
+use std::io::{self, Read, Write};
+
+use crate::invariants::InvariantViolation;
+use crate::static_list::StaticLinkedList;
+
+pub const MAGIC: [u8; 4] = *b"SLLS";
+pub const FORMAT_VERSION: u16 = 1;
+pub const HEADER_SIZE: usize = 32;
+
+// Marks a missing slot index (an empty chain or the end of one).
+const NO_SLOT: u32 = u32::MAX;
+
+// A type that encodes to and decodes from a fixed number of bytes,
+// so it can be stored in a snapshot slot.
+pub trait FixedCodec: Sized {
+    // The number of bytes every encoded value takes.
+    const SIZE: usize;
+
+    // Writes the value into buf, which is exactly SIZE bytes long.
+    fn encode(&self, buf: &mut [u8]);
+
+    // Reads a value from buf, which is exactly SIZE bytes long.
+    // Returns None if the bytes are not a valid encoding.
+    fn decode(buf: &[u8]) -> Option<Self>;
+}
+
+macro_rules! impl_fixed_codec_for_number {
+    ($($t:ty),*) => {
+        $(
+            impl FixedCodec for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                fn encode(&self, buf: &mut [u8]) {
+                    buf.copy_from_slice(&self.to_le_bytes());
+                }
+
+                fn decode(buf: &[u8]) -> Option<Self> {
+                    Some(<$t>::from_le_bytes(buf.try_into().ok()?))
+                }
+            }
+        )*
+    };
+}
+
+impl_fixed_codec_for_number!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+impl FixedCodec for bool {
+    const SIZE: usize = 1;
+
+    fn encode(&self, buf: &mut [u8]) {
+        buf[0] = *self as u8;
+    }
+
+    fn decode(buf: &[u8]) -> Option<Self> {
+        match buf[0] {
+            0 => Some(false),
+            1 => Some(true),
+            _ => None,
+        }
+    }
+}
+
+impl FixedCodec for char {
+    const SIZE: usize = 4;
+
+    fn encode(&self, buf: &mut [u8]) {
+        (*self as u32).encode(buf);
+    }
+
+    fn decode(buf: &[u8]) -> Option<Self> {
+        char::from_u32(u32::decode(buf)?)
+    }
+}
+
+impl<const K: usize> FixedCodec for [u8; K] {
+    const SIZE: usize = K;
+
+    fn encode(&self, buf: &mut [u8]) {
+        buf.copy_from_slice(self);
+    }
+
+    fn decode(buf: &[u8]) -> Option<Self> {
+        buf.try_into().ok()
+    }
+}
+
+// Error returned when a snapshot cannot be written or read back.
+#[derive(Debug)]
+pub enum SnapshotError {
+    // The underlying reader or writer failed, including running out of input.
+    Io(io::Error),
+    // The input does not start with MAGIC.
+    BadMagic,
+    // The snapshot was written by a format version this code does not read.
+    UnsupportedVersion(u16),
+    // The reserved header bytes are not zero.
+    ReservedNotZero(u16),
+    // The snapshot holds a different number of slots than the list type.
+    CapacityMismatch { expected: usize, found: usize },
+    // The snapshot stores elements of a different encoded size than T::SIZE.
+    ElementSizeMismatch { expected: usize, found: usize },
+    // The checksum in the header does not match the contents.
+    ChecksumMismatch { expected: u32, found: u32 },
+    // The capacity does not fit in the 32 bit indices the format uses.
+    CapacityTooLarge(usize),
+    // T::SIZE does not fit in the 32 bit element size field.
+    ElementSizeTooLarge(usize),
+    // A slot's payload is not a valid encoding of T, or a free slot's payload is not zeroed.
+    InvalidPayload { slot: usize },
+    // The slot layout is not a valid list, for example a chain loops or leaves the array.
+    Corrupt(InvariantViolation),
+}
+
+impl fmt::Display for SnapshotError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SnapshotError::Io(err) => write!(f, "snapshot i/o failed: {err}"),
+            SnapshotError::BadMagic => write!(f, "not a static list snapshot"),
+            SnapshotError::UnsupportedVersion(version) => {
+                write!(f, "unsupported snapshot version {version}")
+            }
+            SnapshotError::ReservedNotZero(value) => {
+                write!(f, "reserved header bytes are {value:#06x}, expected 0")
+            }
+            SnapshotError::CapacityMismatch { expected, found } => {
+                write!(f, "snapshot has {found} slots, expected {expected}")
+            }
+            SnapshotError::ElementSizeMismatch { expected, found } => {
+                write!(
+                    f,
+                    "snapshot elements are {found} bytes, expected {expected}"
+                )
+            }
+            SnapshotError::ChecksumMismatch { expected, found } => {
+                write!(
+                    f,
+                    "checksum mismatch: header says {expected:#010x}, contents give {found:#010x}"
+                )
+            }
+            SnapshotError::CapacityTooLarge(capacity) => {
+                write!(f, "capacity {capacity} is too large for a snapshot")
+            }
+            SnapshotError::ElementSizeTooLarge(size) => {
+                write!(f, "element size {size} is too large for a snapshot")
+            }
+            SnapshotError::InvalidPayload { slot } => {
+                write!(f, "slot {slot} holds an invalid value")
+            }
+            SnapshotError::Corrupt(reason) => write!(f, "corrupt snapshot: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for SnapshotError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            SnapshotError::Io(err) => Some(err),
+            SnapshotError::Corrupt(violation) => Some(violation),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for SnapshotError {
+    fn from(err: io::Error) -> Self {
+        SnapshotError::Io(err)
+    }
+}
+
+impl<T: FixedCodec, const N: usize> StaticLinkedList<T, N> {
+    // Writes the list, including its exact slot layout, as a binary snapshot.
+    // writer: impl Write: Where the snapshot goes, for example a File or a Vec<u8>.
+    pub fn write_to<W: Write>(&self, mut writer: W) -> Result<(), SnapshotError> {
+        let capacity = u32::try_from(N)
+            .ok()
+            .filter(|capacity| *capacity != NO_SLOT)
+            .ok_or(SnapshotError::CapacityTooLarge(N))?;
+        let element_size =
+            u32::try_from(T::SIZE).map_err(|_| SnapshotError::ElementSizeTooLarge(T::SIZE))?;
+
+        let record_size = 5 + T::SIZE;
+        let mut bytes = vec![0u8; HEADER_SIZE + N * record_size];
+
+        bytes[0..4].copy_from_slice(&MAGIC);
+        bytes[4..6].copy_from_slice(&FORMAT_VERSION.to_le_bytes());
+        put_u32(&mut bytes[8..12], capacity);
+        put_u32(&mut bytes[12..16], element_size);
+        put_u32(&mut bytes[16..20], encode_index(self.head_slot()));
+        put_u32(&mut bytes[20..24], encode_index(self.free_slot()));
+        put_u32(&mut bytes[24..28], self.len() as u32);
+
+        for (i, (data, next)) in self.slots().enumerate() {
+            let record = &mut bytes[HEADER_SIZE + i * record_size..][..record_size];
+            put_u32(&mut record[0..4], encode_index(next));
+            if let Some(data) = data {
+                record[4] = 1;
+                data.encode(&mut record[5..]);
+            }
+        }
+
+        let checksum = snapshot_checksum(&bytes);
+        put_u32(&mut bytes[28..32], checksum);
+        writer.write_all(&bytes)?;
+        Ok(())
+    }
+
+    // Reads a snapshot written by write_to and rebuilds the list with the same slot layout.
+    // Every header field including the reserved bytes, the checksum, each payload (a free slot's
+    // must be zeroed) and both chains are checked, so corrupted input is reported as an error
+    // rather than producing a broken list.
+    // reader: impl Read: Where the snapshot comes from.
+    pub fn read_from<R: Read>(mut reader: R) -> Result<Self, SnapshotError> {
+        let mut header = [0u8; HEADER_SIZE];
+        reader.read_exact(&mut header)?;
+
+        if header[0..4] != MAGIC {
+            return Err(SnapshotError::BadMagic);
+        }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != FORMAT_VERSION {
+            return Err(SnapshotError::UnsupportedVersion(version));
+        }
+        let reserved = u16::from_le_bytes([header[6], header[7]]);
+        if reserved != 0 {
+            return Err(SnapshotError::ReservedNotZero(reserved));
+        }
+        let capacity = get_u32(&header[8..12]) as usize;
+        if capacity != N {
+            return Err(SnapshotError::CapacityMismatch {
+                expected: N,
+                found: capacity,
+            });
+        }
+        let element_size = get_u32(&header[12..16]) as usize;
+        if element_size != T::SIZE {
+            return Err(SnapshotError::ElementSizeMismatch {
+                expected: T::SIZE,
+                found: element_size,
+            });
+        }
+
+        let record_size = 5 + T::SIZE;
+        let mut bytes = vec![0u8; HEADER_SIZE + N * record_size];
+        bytes[..HEADER_SIZE].copy_from_slice(&header);
+        reader.read_exact(&mut bytes[HEADER_SIZE..])?;
+
+        let expected = get_u32(&header[28..32]);
+        let found = snapshot_checksum(&bytes);
+        if expected != found {
+            return Err(SnapshotError::ChecksumMismatch { expected, found });
+        }
+
+        let mut slots = Vec::with_capacity(N);
+        for i in 0..N {
+            let record = &bytes[HEADER_SIZE + i * record_size..][..record_size];
+            let next = decode_index(get_u32(&record[0..4]));
+            let data = match record[4] {
+                0 if record[5..].iter().all(|byte| *byte == 0) => None,
+                0 => return Err(SnapshotError::InvalidPayload { slot: i }),
+                1 => {
+                    Some(T::decode(&record[5..]).ok_or(SnapshotError::InvalidPayload { slot: i })?)
+                }
+                _ => return Err(SnapshotError::InvalidPayload { slot: i }),
+            };
+            slots.push((data, next));
+        }
+
+        let Ok(slots) = <[(Option<T>, Option<usize>); N]>::try_from(slots) else {
+            unreachable!("one record was read per slot");
+        };
+
+        let head = decode_index(get_u32(&header[16..20]));
+        let free = decode_index(get_u32(&header[20..24]));
+        let list =
+            StaticLinkedList::from_parts(slots, head, free).map_err(SnapshotError::Corrupt)?;
+
+        let live_count = get_u32(&header[24..28]) as usize;
+        if list.len() != live_count {
+            return Err(SnapshotError::Corrupt(
+                InvariantViolation::LengthMismatch {
+                    cached: live_count,
+                    actual: list.len(),
+                },
+            ));
+        }
+        Ok(list)
+    }
+}
+
+fn put_u32(buf: &mut [u8], value: u32) {
+    buf.copy_from_slice(&value.to_le_bytes());
+}
+
+fn get_u32(buf: &[u8]) -> u32 {
+    u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]])
+}
+
+fn encode_index(index: Option<usize>) -> u32 {
+    index.map_or(NO_SLOT, |i| i as u32)
+}
+
+fn decode_index(value: u32) -> Option<usize> {
+    if value == NO_SLOT {
+        None
+    } else {
+        Some(value as usize)
+    }
+}
+
+// The checksum covers the header up to the checksum field and everything after the header.
+fn snapshot_checksum(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(&bytes[..28]);
+    crc.update(&bytes[HEADER_SIZE..]);
+    crc.finish()
+}
+
+// CRC-32 (IEEE 802.3, the polynomial used by zip and PNG), computed with a lookup table.
+pub(crate) struct Crc32 {
+    state: u32,
+}
+
+const CRC_TABLE: [u32; 256] = crc_table();
+
+const fn crc_table() -> [u32; 256] {
+    let mut table = [0u32; 256];
+    let mut i = 0;
+    while i < 256 {
+        let mut value = i as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            value = if value & 1 != 0 {
+                (value >> 1) ^ 0xEDB8_8320
+            } else {
+                value >> 1
+            };
+            bit += 1;
+        }
+        table[i] = value;
+        i += 1;
+    }
+    table
+}
+
+impl Crc32 {
+    pub(crate) fn new() -> Self {
+        Crc32 { state: !0 }
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.state =
+                CRC_TABLE[((self.state ^ *byte as u32) & 0xFF) as usize] ^ (self.state >> 8);
+        }
+    }
+
+    pub(crate) fn finish(&self) -> u32 {
+        !self.state
+    }
+}
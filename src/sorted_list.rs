@@ -0,0 +1,255 @@
+use std::cmp::Ordering;
+use std::fmt;
+use std::marker::PhantomData;
+use std::ops::{Bound, RangeBounds};
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::static_list::{CapacityError, StaticLinkedList};
+use crate::storage::ListStorage;
+
+// How a SortedList treats an element that compares equal to one already in the list.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Duplicates {
+    // Keep both; the new element goes after the existing equal elements.
+    Allow,
+    // Leave the list unchanged and hand the new element back.
+    Reject,
+    // Swap the existing element for the new one and hand the old one back.
+    Replace,
+}
+
+// Error returned when SortedList::insert does not add the element.
+// Both variants give the element back to the caller.
+#[derive(Debug, PartialEq)]
+pub enum InsertError<T> {
+    // An equal element is already in the list and duplicates are rejected.
+    Duplicate(T),
+    // The underlying storage has no space available.
+    Full(T),
+}
+
+impl<T> fmt::Display for InsertError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InsertError::Duplicate(_) => write!(f, "an equal element is already in the list"),
+            InsertError::Full(_) => write!(f, "the list is full"),
+        }
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for InsertError<T> {}
+
+// A list that keeps its elements in ascending order.
+// T: The type of data stored in the list.
+// S: The storage the elements live in, a DynamicLinkedList by default.
+pub struct SortedList<T: Ord, S: ListStorage<T> = DynamicLinkedList<T>> {
+    storage: S,
+    duplicates: Duplicates,
+    marker: PhantomData<T>,
+}
+
+// A SortedList stored in a fixed size StaticLinkedList.
+pub type StaticSortedList<T, const N: usize> = SortedList<T, StaticLinkedList<T, N>>;
+
+impl<T: Ord, S: ListStorage<T>> SortedList<T, S> {
+    // Creates an empty list that allows duplicates.
+    pub fn new() -> Self {
+        Self::with_duplicates(Duplicates::Allow)
+    }
+
+    // Creates an empty list with the given duplicate handling.
+    // duplicates: Duplicates: What insert and merge do with elements equal to ones already present.
+    pub fn with_duplicates(duplicates: Duplicates) -> Self {
+        SortedList {
+            storage: S::default(),
+            duplicates,
+            marker: PhantomData,
+        }
+    }
+
+    // Returns how this list treats duplicate elements.
+    pub fn duplicates(&self) -> Duplicates {
+        self.duplicates
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    // Returns an iterator over the elements in ascending order.
+    pub fn iter(&self) -> S::Iter<'_> {
+        self.storage.iter()
+    }
+
+    // Inserts data at its place in the order.
+    // Returns Ok(None) if the element was added, Ok(Some(old)) if it replaced an equal element,
+    // or an InsertError handing the element back if it was rejected as a duplicate or the list is full.
+    // data: T: The element to insert.
+    pub fn insert(&mut self, data: T) -> Result<Option<T>, InsertError<T>> {
+        // Find the first element that is not less than data, and whether it is equal.
+        let mut index = 0;
+        let mut equal = false;
+        for item in self.storage.iter() {
+            match item.cmp(&data) {
+                Ordering::Less => index += 1,
+                Ordering::Equal => {
+                    equal = true;
+                    break;
+                }
+                Ordering::Greater => break,
+            }
+        }
+
+        if equal {
+            match self.duplicates {
+                Duplicates::Reject => return Err(InsertError::Duplicate(data)),
+                Duplicates::Replace => {
+                    let slot = self.storage.get_mut(index).unwrap();
+                    return Ok(Some(std::mem::replace(slot, data)));
+                }
+                Duplicates::Allow => {
+                    // Go after the existing equal elements so equal elements keep insertion order.
+                    index += self
+                        .storage
+                        .iter()
+                        .skip(index)
+                        .take_while(|item| **item == data)
+                        .count();
+                }
+            }
+        }
+
+        self.storage
+            .insert_at(index, data)
+            .map(|()| None)
+            .map_err(InsertError::Full)
+    }
+
+    // Returns a reference to the smallest element, or None if the list is empty.
+    pub fn first(&self) -> Option<&T> {
+        self.storage.front()
+    }
+
+    // Removes and returns the smallest element, or None if the list is empty.
+    pub fn pop_first(&mut self) -> Option<T> {
+        self.storage.pop_front()
+    }
+
+    // Returns true if an element equal to data is in the list.
+    // The search stops as soon as it passes the place data would be.
+    // data: &T: The element to look for.
+    pub fn contains(&self, data: &T) -> bool {
+        self.storage
+            .iter()
+            .find(|item| *item >= data)
+            .is_some_and(|item| item == data)
+    }
+
+    // Returns an iterator over the elements that fall within range, in ascending order.
+    // The iteration stops at the first element past the end of the range.
+    // range: R: The bounds to iterate over, for example a..b or a..=b.
+    pub fn range<R: RangeBounds<T>>(&self, range: R) -> Range<'_, T, S::Iter<'_>, R> {
+        Range {
+            iter: self.storage.iter(),
+            range,
+        }
+    }
+
+    // Moves every element of other into this list in linear time, leaving other empty.
+    // Equal elements are handled by this list's duplicate setting, including ones repeated within
+    // other: with Reject the first of them is kept, which is the one already in this list if there
+    // is one; with Replace the last one from other wins.
+    // Returns an error carrying the elements that did not fit if the storage runs out of space;
+    // the elements merged so far stay in this list.
+    // other: &mut SortedList<T, S>: The list whose elements are moved.
+    pub fn merge(&mut self, other: &mut SortedList<T, S>) -> Result<(), CapacityError<T>> {
+        let mut merged = S::default();
+        let mut leftover = Vec::new();
+        let mut push = |data| {
+            if let Err(data) = merged.push_back(data) {
+                leftover.push(data);
+            }
+        };
+        // The element taken last, held back until the next one shows whether it is a duplicate.
+        let mut last: Option<T> = None;
+
+        loop {
+            // On a tie this list's element goes first, so it is the one kept or replaced.
+            let data = match (self.storage.front(), other.storage.front()) {
+                (Some(a), Some(b)) if a <= b => self.storage.pop_front(),
+                (Some(_), Some(_)) | (None, Some(_)) => other.storage.pop_front(),
+                (Some(_), None) => self.storage.pop_front(),
+                (None, None) => break,
+            };
+            let Some(data) = data else { break };
+
+            last = match last.take() {
+                Some(previous) if previous == data => match self.duplicates {
+                    Duplicates::Allow => {
+                        push(previous);
+                        Some(data)
+                    }
+                    Duplicates::Reject => Some(previous),
+                    Duplicates::Replace => Some(data),
+                },
+                Some(previous) => {
+                    push(previous);
+                    Some(data)
+                }
+                None => Some(data),
+            };
+        }
+        if let Some(data) = last {
+            push(data);
+        }
+
+        self.storage = merged;
+        if leftover.is_empty() {
+            Ok(())
+        } else {
+            Err(CapacityError { leftover })
+        }
+    }
+}
+
+impl<T: Ord, S: ListStorage<T>> Default for SortedList<T, S> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Iterator over the elements of a SortedList that fall within a range.
+pub struct Range<'a, T: 'a, I: Iterator<Item = &'a T>, R: RangeBounds<T>> {
+    iter: I,
+    range: R,
+}
+
+impl<'a, T: Ord + 'a, I: Iterator<Item = &'a T>, R: RangeBounds<T>> Iterator
+    for Range<'a, T, I, R>
+{
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            let item = self.iter.next()?;
+            if self.range.contains(item) {
+                return Some(item);
+            }
+            // The elements are sorted, so anything past the end means there are no more matches.
+            let past_end = match self.range.end_bound() {
+                Bound::Included(end) => item > end,
+                Bound::Excluded(end) => item >= end,
+                Bound::Unbounded => false,
+            };
+            if past_end {
+                return None;
+            }
+        }
+    }
+}
@@ -1,63 +1,821 @@
+use std::cmp::Ordering;
+use std::fmt;
+
+use crate::invariants::{Chain, InvariantViolation};
+use crate::storage::ListStorage;
+
 // Static linked list implementation
 pub struct StaticNode<T> {
-    data: Option<T>,
-    next: Option<usize>,
+    pub(crate) data: Option<T>,
+    pub(crate) next: Option<usize>,
+}
+
+// The free-list machinery behind StaticLinkedList, shared with the other slot based structures
+// in this crate. Unused slots are chained through next from a free index; a slot holds data
+// exactly while it is off the free list.
+impl<T> StaticNode<T> {
+    // Returns an unused slot at index in an array of len slots, chained to the next one,
+    // so that the slots built in index order form a free list starting at slot 0.
+    pub(crate) fn vacant(index: usize, len: usize) -> Self {
+        StaticNode {
+            data: None,
+            next: if index + 1 < len { Some(index + 1) } else { None }, // End of free list
+        }
+    }
+
+    // Takes a slot off the free list starting at free and stores data in it.
+    // Returns the slot index, or gives the data back if there is no space available.
+    pub(crate) fn alloc(nodes: &mut [Self], free: &mut Option<usize>, data: T) -> Result<usize, T> {
+        let index = match *free {
+            Some(i) => i,
+            None => return Err(data),
+        };
+
+        *free = nodes[index].next;
+        nodes[index].data = Some(data);
+        nodes[index].next = None;
+        Ok(index)
+    }
+
+    // Clears a slot that is no longer linked, returns it to the free list and returns its data.
+    pub(crate) fn release(nodes: &mut [Self], free: &mut Option<usize>, index: usize) -> T {
+        let data = nodes[index].data.take();
+        nodes[index].next = *free;
+        *free = Some(index);
+        data.expect("released slot must be occupied")
+    }
 }
 
 // Static linked list structure
 // T: The type of data stored in the list.
 // N: The maximum number of nodes in the list.
 // The size of the array is fixed at compile time, making it a static linked list.
+// Occupied slots are chained from head to tail, unused slots are chained from free.
 pub struct StaticLinkedList<T, const N: usize> {
     nodes: [StaticNode<T>; N],
     head: Option<usize>,
+    tail: Option<usize>,
     free: Option<usize>,
+    len: usize,
 }
 
-/// Implementation of the StaticLinkedList structure
-/// This implementation provides methods to create a new static linked list
-impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
-    pub fn new() -> Self {
-        let mut nodes: [StaticNode<T>; N] = std::array::from_fn(|i| StaticNode {
-            data: None,
-            next: Some(i + 1),
-        });
+// Error returned when the destination list runs out of free slots part way through
+// a bulk operation. The elements that did not fit are handed back in order.
+#[derive(Debug, PartialEq)]
+pub struct CapacityError<T> {
+    pub leftover: Vec<T>,
+}
 
-        nodes[N - 1].next = None; // End of free list
+impl<T> fmt::Display for CapacityError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "static list is full, {} element(s) did not fit",
+            self.leftover.len()
+        )
+    }
+}
+
+impl<T: fmt::Debug> std::error::Error for CapacityError<T> {}
+
+// Methods that only restructure the list and never compare or copy data,
+// so they are available for any type T.
+impl<T, const N: usize> StaticLinkedList<T, N> {
+    pub fn new() -> Self {
+        // Every slot starts out on the free list, chained in index order.
+        let nodes: [StaticNode<T>; N] = std::array::from_fn(|i| StaticNode::vacant(i, N));
 
         Self {
             nodes,
             head: None,
-            free: Some(0),
+            tail: None,
+            free: if N > 0 { Some(0) } else { None },
+            len: 0,
         }
     }
 
-    /// Retrieves the data at the specified index in the list.
-    /// Returns an Option<T>, which is Some(data) if the index is valid, or None if it is out of bounds.
-    /// mut self: This indicates that the method is borrowing the instance of the struct.
-    /// data: T: This is a generic type parameter, meaning that the method can accept any type T.
-    pub fn insert(&mut self, data: T) {
-        // No space available
-        let free_index = match self.free {
-            Some(i) => i,
-            None => return,
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns the maximum number of elements the list can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    // Returns true if every slot is in use.
+    pub fn is_full(&self) -> bool {
+        self.free.is_none()
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T, N> {
+        Iter {
+            list: self,
+            current: self.head,
+            remaining: self.len,
+        }
+    }
+
+    // Returns every slot in array order as (data, next index), including free ones.
+    // Along with head_slot and free_slot this exposes the raw layout for debugging and snapshots.
+    pub fn slots(&self) -> impl ExactSizeIterator<Item = (Option<&T>, Option<usize>)> + '_ {
+        self.nodes.iter().map(|node| (node.data.as_ref(), node.next))
+    }
+
+    // Returns the slot index of the first element, or None if the list is empty.
+    pub fn head_slot(&self) -> Option<usize> {
+        self.head
+    }
+
+    // Returns the slot index at the top of the free list, or None if the list is full.
+    pub fn free_slot(&self) -> Option<usize> {
+        self.free
+    }
+
+    // Rebuilds a list from a raw slot layout, as produced by slots, head_slot and free_slot.
+    // The layout is checked with validate before it is accepted.
+    // Returns the first invariant the layout breaks if it is not a valid list.
+    pub(crate) fn from_parts(
+        slots: [(Option<T>, Option<usize>); N],
+        head: Option<usize>,
+        free: Option<usize>,
+    ) -> Result<Self, InvariantViolation> {
+        let nodes = slots.map(|(data, next)| StaticNode { data, next });
+
+        // Work out the length and tail from the live chain, stopping early if it is broken;
+        // validate reports exactly what is wrong.
+        let mut len = 0;
+        let mut tail = None;
+        let mut current = head;
+        while let Some(index) = current {
+            if index >= N || len == N {
+                break;
+            }
+            len += 1;
+            tail = Some(index);
+            current = nodes[index].next;
+        }
+
+        let list = StaticLinkedList {
+            nodes,
+            head,
+            tail,
+            free,
+            len,
+        };
+        list.validate()?;
+        Ok(list)
+    }
+
+    // Checks every structural invariant of the list: both chains stay inside the array and
+    // never loop, no slot is on both chains or on neither, live slots hold data and free slots
+    // do not, and the cached length and tail match the live chain.
+    // Returns the first violation found. Runs in O(N).
+    pub fn validate(&self) -> Result<(), InvariantViolation> {
+        let mut on_live = vec![false; N];
+        let mut len = 0;
+        let mut last = None;
+        let mut current = self.head;
+        while let Some(index) = current {
+            if index >= N {
+                return Err(InvariantViolation::IndexOutOfRange {
+                    chain: Chain::Live,
+                    index,
+                });
+            }
+            if on_live[index] {
+                return Err(InvariantViolation::Cycle {
+                    chain: Chain::Live,
+                    slot: index,
+                });
+            }
+            if self.nodes[index].data.is_none() {
+                return Err(InvariantViolation::EmptyLiveSlot { slot: index });
+            }
+            on_live[index] = true;
+            len += 1;
+            last = Some(index);
+            current = self.nodes[index].next;
+        }
+
+        let mut on_free = vec![false; N];
+        let mut current = self.free;
+        while let Some(index) = current {
+            if index >= N {
+                return Err(InvariantViolation::IndexOutOfRange {
+                    chain: Chain::Free,
+                    index,
+                });
+            }
+            if on_free[index] {
+                return Err(InvariantViolation::Cycle {
+                    chain: Chain::Free,
+                    slot: index,
+                });
+            }
+            if on_live[index] {
+                return Err(InvariantViolation::SharedSlot { slot: index });
+            }
+            if self.nodes[index].data.is_some() {
+                return Err(InvariantViolation::OccupiedFreeSlot { slot: index });
+            }
+            on_free[index] = true;
+            current = self.nodes[index].next;
+        }
+
+        if let Some(slot) = (0..N).find(|i| !on_live[*i] && !on_free[*i]) {
+            return Err(InvariantViolation::OrphanedSlot { slot });
+        }
+        if len != self.len {
+            return Err(InvariantViolation::LengthMismatch {
+                cached: self.len,
+                actual: len,
+            });
+        }
+        if last != self.tail {
+            return Err(InvariantViolation::TailMismatch {
+                tail: self.tail,
+                last,
+            });
+        }
+        Ok(())
+    }
+
+    // Panics if the list breaks an invariant. Only does anything with the
+    // debug-invariants feature, so it is free to call after every mutation.
+    #[inline]
+    fn check_invariants(&self) {
+        #[cfg(feature = "debug-invariants")]
+        if let Err(violation) = self.validate() {
+            panic!("StaticLinkedList invariant violated: {violation}");
+        }
+    }
+
+    // Takes a slot off the free list and stores data in it.
+    // Returns the slot index, or gives the data back if there is no space available.
+    fn alloc(&mut self, data: T) -> Result<usize, T> {
+        StaticNode::alloc(&mut self.nodes, &mut self.free, data)
+    }
+
+    // Clears a slot that is no longer linked and returns it to the free list.
+    fn release(&mut self, index: usize) -> T {
+        StaticNode::release(&mut self.nodes, &mut self.free, index)
+    }
+
+    // Returns the slot index of the element at the specified position, or None if out of bounds.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+        let mut current = self.head?;
+        for _ in 0..index {
+            current = self.nodes[current].next?;
+        }
+        Some(current)
+    }
+
+    // Links an allocated slot after prev, or at the head if prev is None.
+    fn link_after(&mut self, prev: Option<usize>, index: usize) {
+        let next = match prev {
+            Some(p) => self.nodes[p].next.replace(index),
+            None => self.head.replace(index),
         };
+        self.nodes[index].next = next;
+        if next.is_none() {
+            self.tail = Some(index);
+        }
+        self.len += 1;
+        self.check_invariants();
+    }
+
+    // Unlinks the slot after prev (or the head if prev is None), frees it and returns its data.
+    fn unlink_after(&mut self, prev: Option<usize>) -> Option<T> {
+        let index = match prev {
+            Some(p) => self.nodes[p].next?,
+            None => self.head?,
+        };
+        let next = self.nodes[index].next;
+        match prev {
+            Some(p) => self.nodes[p].next = next,
+            None => self.head = next,
+        }
+        if next.is_none() {
+            self.tail = prev;
+        }
+        self.len -= 1;
+        let data = self.release(index);
+        self.check_invariants();
+        Some(data)
+    }
+
+    // Appends data at the tail in O(1).
+    // Returns the data back if there is no space available.
+    pub(crate) fn push_back(&mut self, data: T) -> Result<(), T> {
+        let index = self.alloc(data)?;
+        self.link_after(self.tail, index);
+        Ok(())
+    }
+
+    // Removes the head element and returns its data, or None if the list is empty.
+    pub(crate) fn pop_front(&mut self) -> Option<T> {
+        self.unlink_after(None)
+    }
+
+    // Unlinks the element at the specified index and returns its data.
+    // Returns None if the index is out of bounds.
+    pub(crate) fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let prev = if index == 0 {
+            None
+        } else {
+            self.slot_at(index - 1)
+        };
+        self.unlink_after(prev)
+    }
+
+    // Moves the elements of other onto the end of this list, in order, leaving other empty.
+    // Returns an error carrying the elements that did not fit if this list runs out of free slots.
+    // other: &mut StaticLinkedList<T, M>: The list whose elements are moved; it may have a different capacity.
+    pub fn append<const M: usize>(
+        &mut self,
+        other: &mut StaticLinkedList<T, M>,
+    ) -> Result<(), CapacityError<T>> {
+        while let Some(data) = other.pop_front() {
+            if let Err(data) = self.push_back(data) {
+                return Err(other.drain_into_error(data));
+            }
+        }
+        Ok(())
+    }
+
+    // Splits the list in two at the given index.
+    // Returns a new list holding the elements from index at onwards; this list keeps [0, at).
+    // If at is past the end of the list nothing is split off and an empty list is returned.
+    // at: usize: The index of the first element that moves to the new list.
+    pub fn split_off(&mut self, at: usize) -> Self {
+        let mut rest = Self::new();
+        if at >= self.len {
+            return rest;
+        }
+
+        let prev = if at == 0 { None } else { self.slot_at(at - 1) };
+        while let Some(data) = self.unlink_after(prev) {
+            // rest has the same capacity as self, so it can always hold the split elements.
+            if rest.push_back(data).is_err() {
+                unreachable!("split list has the same capacity");
+            }
+        }
+        rest
+    }
+
+    // Moves the elements of other into this list so the first of them ends up at index.
+    // Returns Ok(true) if the elements were spliced in, Ok(false) if the index is out of bounds
+    // (in which case other is left untouched), or an error carrying the elements that did not fit.
+    // index: usize: The position the first element of other will occupy.
+    // other: &mut StaticLinkedList<T, M>: The list whose elements are moved; it may have a different capacity.
+    pub fn splice_at<const M: usize>(
+        &mut self,
+        index: usize,
+        other: &mut StaticLinkedList<T, M>,
+    ) -> Result<bool, CapacityError<T>> {
+        if index > self.len {
+            return Ok(false);
+        }
+
+        let mut prev = if index == 0 {
+            None
+        } else {
+            self.slot_at(index - 1)
+        };
+        while let Some(data) = other.pop_front() {
+            match self.alloc(data) {
+                Ok(slot) => {
+                    self.link_after(prev, slot);
+                    prev = Some(slot);
+                }
+                Err(data) => return Err(other.drain_into_error(data)),
+            }
+        }
+        Ok(true)
+    }
+
+    // Shortens the list to the first len elements, returning the rest of the slots to the free list.
+    // If len is greater than or equal to the current length this has no effect.
+    // len: usize: The number of elements to keep.
+    pub fn truncate(&mut self, len: usize) {
+        if len >= self.len {
+            return;
+        }
+
+        let prev = if len == 0 { None } else { self.slot_at(len - 1) };
+        while self.unlink_after(prev).is_some() {}
+    }
+
+    // Removes every element, returning all slots to the free list.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    // Empties the list into a CapacityError, with first as the element that failed to fit.
+    fn drain_into_error(&mut self, first: T) -> CapacityError<T> {
+        let mut leftover = Vec::with_capacity(self.len + 1);
+        leftover.push(first);
+        while let Some(data) = self.pop_front() {
+            leftover.push(data);
+        }
+        CapacityError { leftover }
+    }
+}
+
+// Sorting. Only the next indices are rewritten by a bottom-up merge sort; the data never
+// moves between slots and no extra memory is allocated.
+impl<T, const N: usize> StaticLinkedList<T, N> {
+    // Sorts the list in ascending order. The sort is stable.
+    pub fn sort(&mut self)
+    where
+        T: Ord,
+    {
+        self.sort_by(T::cmp);
+    }
+
+    // Sorts the list by the key extracted from each element. The sort is stable.
+    // f: FnMut(&T) -> K: Extracts the key to compare elements by.
+    pub fn sort_by_key<K: Ord, F: FnMut(&T) -> K>(&mut self, mut f: F) {
+        self.sort_by(|a, b| f(a).cmp(&f(b)));
+    }
+
+    // Sorts the list with a comparator function in O(n log n). The sort is stable:
+    // elements that compare equal keep their original order.
+    // cmp: FnMut(&T, &T) -> Ordering: Compares two elements.
+    pub fn sort_by<F: FnMut(&T, &T) -> Ordering>(&mut self, mut cmp: F) {
+        if self.len < 2 {
+            return;
+        }
+
+        let len = self.len;
+        let mut sort = SortGuard {
+            rest: self.head.take(),
+            list: self,
+            merged: None,
+            merged_tail: None,
+            left: None,
+            right: None,
+        };
+        let mut width = 1;
+        while width < len {
+            // Merge neighbouring runs of width slots into runs of 2 * width slots.
+            while sort.rest.is_some() {
+                sort.left = sort.rest.take();
+                sort.right = sort.list.split_run(sort.left, width);
+                sort.rest = sort.list.split_run(sort.right, width);
+                sort.merge_runs(&mut cmp);
+            }
+            sort.rest = sort.merged.take();
+            sort.merged_tail = None;
+            width *= 2;
+        }
 
-        // Take next from free list
-        self.free = self.nodes[free_index].next;
-        self.nodes[free_index].data = Some(data);
-        self.nodes[free_index].next = None;
+        drop(sort);
+        self.check_invariants();
+    }
+
+    // Returns true if the elements are in ascending order.
+    pub fn is_sorted(&self) -> bool
+    where
+        T: PartialOrd,
+    {
+        self.iter().is_sorted()
+    }
+
+    // Cuts the chain starting at start after (at most) n slots.
+    // Returns the first slot of the remainder of the chain.
+    fn split_run(&mut self, start: Option<usize>, n: usize) -> Option<usize> {
+        let mut current = start?;
+        for _ in 1..n {
+            current = self.nodes[current].next?;
+        }
+        self.nodes[current].next.take()
+    }
 
-        match self.head {
-            None => self.head = Some(free_index), // First insert
-            Some(mut current_index) => {
-                while let Some(next_index) = self.nodes[current_index].next {
-                    current_index = next_index;
+    // Returns the data in an occupied slot.
+    fn data(&self, index: usize) -> &T {
+        self.nodes[index].data.as_ref().expect("linked slot must be occupied")
+    }
+}
+
+// Holds the chains of a list while sort_by relinks them: the runs merged so far, by first and
+// last slot, the two runs being merged and the rest. Dropping it joins the chains back up in that
+// order and recomputes the tail and length, so if the comparator panics every occupied slot is
+// still linked, though the order of the list is unspecified.
+struct SortGuard<'a, T, const N: usize> {
+    list: &'a mut StaticLinkedList<T, N>,
+    merged: Option<usize>,
+    merged_tail: Option<usize>,
+    left: Option<usize>,
+    right: Option<usize>,
+    rest: Option<usize>,
+}
+
+impl<T, const N: usize> SortGuard<'_, T, N> {
+    // Merges the sorted runs left and right onto the end of merged, taking from left on ties to
+    // keep the sort stable. A slot is always in exactly one of the chains, even mid-merge.
+    fn merge_runs<F: FnMut(&T, &T) -> Ordering>(&mut self, cmp: &mut F) {
+        loop {
+            let take_left = match (self.left, self.right) {
+                (Some(a), Some(b)) => cmp(self.list.data(b), self.list.data(a)) != Ordering::Less,
+                (Some(_), None) => true,
+                (None, Some(_)) => false,
+                (None, None) => return,
+            };
+            let source = if take_left { &mut self.left } else { &mut self.right };
+            let index = source.expect("chosen run is not empty");
+            *source = self.list.nodes[index].next.take();
+            match self.merged_tail {
+                Some(tail) => self.list.nodes[tail].next = Some(index),
+                None => self.merged = Some(index),
+            }
+            self.merged_tail = Some(index);
+        }
+    }
+}
+
+impl<T, const N: usize> Drop for SortGuard<'_, T, N> {
+    fn drop(&mut self) {
+        let list = &mut *self.list;
+        let mut head = None;
+        let mut tail: Option<usize> = None;
+        let mut len = 0;
+        for mut piece in [self.merged, self.left, self.right, self.rest] {
+            while let Some(index) = piece {
+                match tail {
+                    Some(t) => list.nodes[t].next = Some(index),
+                    None => head = Some(index),
                 }
-                self.nodes[current_index].next = Some(free_index); // Append to tail
+                tail = Some(index);
+                len += 1;
+                piece = list.nodes[index].next;
             }
         }
-        
+        list.head = head;
+        list.tail = tail;
+        list.len = len;
+    }
+}
+
+// Classic list algorithms working on the StaticNode::next indices.
+impl<T, const N: usize> StaticLinkedList<T, N> {
+    // Reverses the order of the elements by rewriting next indices; no data moves between slots.
+    pub fn reverse(&mut self) {
+        let mut reversed = None;
+        let mut rest = self.head;
+        while let Some(index) = rest {
+            rest = self.nodes[index].next;
+            self.nodes[index].next = reversed;
+            reversed = Some(index);
+        }
+        self.tail = self.head;
+        self.head = reversed;
+        self.check_invariants();
+    }
+
+    // Rotates the list so the element at index k becomes the head.
+    // k is taken modulo the length, so any k is accepted. Only next indices are rewritten.
+    // k: usize: The number of elements moved from the front to the back.
+    pub fn rotate_left(&mut self, k: usize) {
+        if self.len < 2 || k.is_multiple_of(self.len) {
+            return;
+        }
+        let (Some(new_tail), Some(old_tail)) = (self.slot_at(k % self.len - 1), self.tail) else {
+            return;
+        };
+
+        self.nodes[old_tail].next = self.head;
+        self.head = self.nodes[new_tail].next.take();
+        self.tail = Some(new_tail);
+        self.check_invariants();
+    }
+
+    // Rotates the list so the last k elements move to the front.
+    // k is taken modulo the length, so any k is accepted.
+    // k: usize: The number of elements moved from the back to the front.
+    pub fn rotate_right(&mut self, k: usize) {
+        if self.len < 2 {
+            return;
+        }
+        self.rotate_left(self.len - k % self.len);
+    }
+
+    // Removes consecutive duplicate elements, keeping the first of each run.
+    // The slots of removed elements go back to the free list.
+    pub fn dedup(&mut self)
+    where
+        T: PartialEq,
+    {
+        self.dedup_by(|a, b| a == b);
+    }
+
+    // Removes consecutive elements that map to the same key, keeping the first of each run.
+    // key: FnMut(&T) -> K: Extracts the key to compare neighbouring elements by.
+    pub fn dedup_by_key<K: PartialEq, F: FnMut(&T) -> K>(&mut self, mut key: F) {
+        self.dedup_by(|a, b| key(a) == key(b));
+    }
+
+    // Removes consecutive elements for which same_bucket returns true, keeping the first of each run.
+    // same_bucket: FnMut(&T, &T) -> bool: Called with the candidate for removal and the element kept before it.
+    pub fn dedup_by<F: FnMut(&T, &T) -> bool>(&mut self, mut same_bucket: F) {
+        let Some(mut current) = self.head else {
+            return;
+        };
+        while let Some(next) = self.nodes[current].next {
+            if same_bucket(self.data(next), self.data(current)) {
+                self.unlink_after(Some(current));
+            } else {
+                current = next;
+            }
+        }
+    }
+
+    // Swaps the data held at indices i and j.
+    // Returns true if both indices are in bounds, false otherwise (and the list is unchanged).
+    // i: usize, j: usize: The positions to swap.
+    pub fn swap(&mut self, i: usize, j: usize) -> bool {
+        let (Some(a), Some(b)) = (self.slot_at(i), self.slot_at(j)) else {
+            return false;
+        };
+        if a != b {
+            let data = self.nodes[a].data.take();
+            self.nodes[a].data = self.nodes[b].data.take();
+            self.nodes[b].data = data;
+        }
+        self.check_invariants();
+        true
+    }
+
+    // Returns the element k positions from the back (k = 0 is the last element),
+    // or None if the list is too short. Uses two indices k slots apart, so the
+    // list is walked once without relying on its length.
+    // k: usize: The distance from the last element.
+    pub fn nth_from_back(&self, k: usize) -> Option<&T> {
+        let mut lead = self.head?;
+        for _ in 0..k {
+            lead = self.nodes[lead].next?;
+        }
+
+        let mut trail = self.head?;
+        while let Some(next) = self.nodes[lead].next {
+            lead = next;
+            trail = self.nodes[trail].next?;
+        }
+        self.nodes[trail].data.as_ref()
+    }
+
+    // Returns the middle element, or None if the list is empty. For an even number
+    // of elements this is the second of the two middle ones. Uses a slow index
+    // and a fast index moving twice as quickly.
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self.head?;
+        let mut fast = self.head?;
+        while let Some(next) = self.nodes[fast].next {
+            slow = self.nodes[slow].next?;
+            match self.nodes[next].next {
+                Some(after) => fast = after,
+                None => break,
+            }
+        }
+        self.nodes[slow].data.as_ref()
+    }
+
+    // Returns true if following next indices from head ever revisits a slot
+    // (Floyd's cycle detection). A correctly built list never has a cycle;
+    // this is a debugging aid.
+    pub fn has_cycle(&self) -> bool {
+        let mut slow = self.head;
+        let mut fast = self.head;
+        loop {
+            fast = match fast.and_then(|i| self.nodes[i].next) {
+                Some(i) => self.nodes[i].next,
+                None => return false,
+            };
+            slow = slow.and_then(|i| self.nodes[i].next);
+            match fast {
+                Some(_) if slow == fast => return true,
+                None => return false,
+                _ => {}
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> Default for StaticLinkedList<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> ListStorage<T> for StaticLinkedList<T, N> {
+    type Iter<'a>
+        = Iter<'a, T, N>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter(&self) -> Iter<'_, T, N> {
+        StaticLinkedList::iter(self)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let slot = self.slot_at(index)?;
+        self.nodes[slot].data.as_mut()
+    }
+
+    fn push_back(&mut self, data: T) -> Result<(), T> {
+        StaticLinkedList::push_back(self, data)
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) -> Result<(), T> {
+        // Check the bounds before taking a slot so a rejected insert never leaks one
+        if index > self.len {
+            return Err(data);
+        }
+        let prev = if index == 0 {
+            None
+        } else {
+            self.slot_at(index - 1)
+        };
+        let slot = self.alloc(data)?;
+        self.link_after(prev, slot);
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        StaticLinkedList::pop_front(self)
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<T> {
+        self.remove(index)
+    }
+
+    fn clear(&mut self) {
+        StaticLinkedList::clear(self);
+    }
+}
+
+// Iterator over references to the data in a StaticLinkedList, from head to tail.
+pub struct Iter<'a, T, const N: usize> {
+    list: &'a StaticLinkedList<T, N>,
+    current: Option<usize>,
+    remaining: usize,
+}
+
+impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let index = self.current?;
+        let node = &self.list.nodes[index];
+        self.current = node.next;
+        self.remaining -= 1;
+        node.data.as_ref()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Iter<'_, T, N> {}
+
+impl<'a, T, const N: usize> IntoIterator for &'a StaticLinkedList<T, N> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N>;
+
+    fn into_iter(self) -> Iter<'a, T, N> {
+        self.iter()
+    }
+}
+
+/// Implementation of the StaticLinkedList structure
+/// This implementation provides methods to create a new static linked list
+impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
+    /// Retrieves the data at the specified index in the list.
+    /// Returns an Option<T>, which is Some(data) if the index is valid, or None if it is out of bounds.
+    /// mut self: This indicates that the method is borrowing the instance of the struct.
+    /// data: T: This is a generic type parameter, meaning that the method can accept any type T.
+    pub fn insert(&mut self, data: T) {
+        // No space available: the data is dropped
+        let _ = self.push_back(data);
     }
 
     // Gets the data at the specified index in the list.
@@ -81,38 +839,10 @@ impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
     // index: usize: This is the index where we want to insert the new node.
     // data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn insert_at_index(&mut self, index: usize, data: T) {
-        let new_index = match self.free {
-            Some(i) => i,
-            None => return,
-        };
-    
-        // Update free list
-        self.free = self.nodes[new_index].next;
-        self.nodes[new_index].data = Some(data);
-        self.nodes[new_index].next = None;
-    
-        // Insert at head
-        if index == 0 {
-            self.nodes[new_index].next = self.head;
-            self.head = Some(new_index);
-            return;
-        }
-    
-        // Traverse to (index - 1)
-        let mut current = self.head;
-        for _ in 0..index - 1 {
-            current = match current {
-                Some(i) => self.nodes[i].next,
-                None => return, // Out of bounds
-            };
-        }
-    
-        if let Some(prev_index) = current {
-            self.nodes[new_index].next = self.nodes[prev_index].next;
-            self.nodes[prev_index].next = Some(new_index);
-        }
+        // Out of bounds or no space available: the list is unchanged
+        let _ = self.insert_at(index, data);
     }
-    
+
     // Deletes the first occurrence of the specified element from the list.
     // Returns true if the element was found and deleted, false otherwise.
     // self: This indicates that the method is borrowing the instance of the struct.
@@ -120,71 +850,27 @@ impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
     pub fn delete_element(&mut self, data: T) -> bool {
         let mut prev: Option<usize> = None;
         let mut current = self.head;
-    
+
         while let Some(index) = current {
-            if self.nodes[index].data == Some(data.clone()) {
-                let next = self.nodes[index].next;
-    
-                // Unlink
-                if let Some(prev_index) = prev {
-                    self.nodes[prev_index].next = next;
-                } else {
-                    self.head = next;
-                }
-    
-                // Clear and return node to free list
-                self.nodes[index].data = None;
-                self.nodes[index].next = self.free;
-                self.free = Some(index);
-    
+            if self.nodes[index].data.as_ref() == Some(&data) {
+                // Unlink and return node to free list
+                self.unlink_after(prev);
                 return true;
             }
-    
+
             prev = current;
             current = self.nodes[index].next;
         }
-    
+
         false
     }
-    
+
     // Deletes the node at the specified index from the list.
     // Returns true if the node was found and deleted, false otherwise.
     // self: This indicates that the method is borrowing the instance of the struct.
     // index: usize: This is the index of the node we want to delete from the list.
     pub fn delete_at_index(&mut self, index: usize) -> bool {
-        let mut current = self.head;
-        let mut prev = None;
-    
-        for _ in 0..index {
-            match current {
-                Some(i) => {
-                    prev = current;
-                    current = self.nodes[i].next;
-                }
-                None => return false, // Out of bounds
-            }
-        }
-    
-        match current {
-            Some(i) => {
-                let next = self.nodes[i].next;
-    
-                // Re-link
-                if let Some(prev_i) = prev {
-                    self.nodes[prev_i].next = next;
-                } else {
-                    self.head = next;
-                }
-    
-                // Return node to free list
-                self.nodes[i].data = None;
-                self.nodes[i].next = self.free;
-                self.free = Some(i);
-    
-                true
-            }
-            None => false,
-        }
+        self.remove(index).is_some()
     }
 
     // Updates the first occurrence of the specified element in the list with new data.
@@ -194,15 +880,15 @@ impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
     //// new_data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn update_element(&mut self, old_data: T, new_data: T) -> bool {
         let mut current = self.head;
-    
+
         while let Some(i) = current {
-            if self.nodes[i].data == Some(old_data.clone()) {
+            if self.nodes[i].data.as_ref() == Some(&old_data) {
                 self.nodes[i].data = Some(new_data);
                 return true;
             }
             current = self.nodes[i].next;
         }
-    
+
         false
     }
 
@@ -213,40 +899,51 @@ impl<T: Clone + PartialEq, const N: usize> StaticLinkedList<T, N> {
     // data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn update_element_at_index(&mut self, index: usize, data: T) -> bool {
         let mut current = self.head;
-    
+
         for _ in 0..index {
             current = match current {
                 Some(i) => self.nodes[i].next,
                 None => return false,
             };
         }
-    
+
         if let Some(i) = current {
             self.nodes[i].data = Some(data);
             return true;
         }
-    
+
         false
     }
-    
+
     // Finds the first occurrence of the specified element in the list.
     // Returns true if the element was found, false otherwise.
     // self: This indicates that the method is borrowing the instance of the struct.
     // data: T: This is a generic type parameter, meaning that the method can accept any type T.
     pub fn find(&self, data: T) -> bool {
         let mut current = self.head;
-    
+
         while let Some(i) = current {
-            if self.nodes[i].data == Some(data.clone()) {
+            if self.nodes[i].data.as_ref() == Some(&data) {
                 return true;
             }
             current = self.nodes[i].next;
         }
-    
+
         false
     }
-    
-    
+
+
 
 }
 
+// Test-only hooks that break the cached bookkeeping so validate can be exercised.
+#[cfg(test)]
+impl<T, const N: usize> StaticLinkedList<T, N> {
+    pub(crate) fn corrupt_len(&mut self, len: usize) {
+        self.len = len;
+    }
+
+    pub(crate) fn corrupt_tail(&mut self, tail: Option<usize>) {
+        self.tail = tail;
+    }
+}
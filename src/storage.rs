@@ -0,0 +1,195 @@
+use std::collections::LinkedList;
+use std::collections::linked_list;
+
+use crate::static_list::StaticNode;
+
+// The ListStorage trait describes the operations every list type in this crate
+// provides, so wrappers such as SortedList can be written once and used over
+// either the heap allocated DynamicLinkedList or the fixed size StaticLinkedList.
+pub trait ListStorage<T>: Default {
+    // Iterator over references to the data, from head to tail.
+    type Iter<'a>: Iterator<Item = &'a T>
+    where
+        Self: 'a,
+        T: 'a;
+
+    // Returns the number of elements in the list.
+    fn len(&self) -> usize;
+
+    // Returns true if the list has no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    fn iter(&self) -> Self::Iter<'_>;
+
+    // Returns a reference to the first element, or None if the list is empty.
+    fn front(&self) -> Option<&T> {
+        self.iter().next()
+    }
+
+    // Returns a mutable reference to the element at the specified index, or None if out of bounds.
+    fn get_mut(&mut self, index: usize) -> Option<&mut T>;
+
+    // Appends data at the tail in O(1).
+    // Returns the data back if there is no space available.
+    fn push_back(&mut self, data: T) -> Result<(), T>;
+
+    // Inserts data so it ends up at the specified index.
+    // Returns the data back if the index is out of bounds or there is no space available.
+    fn insert_at(&mut self, index: usize, data: T) -> Result<(), T>;
+
+    // Inserts data at the head in O(1).
+    // Returns the data back if there is no space available.
+    fn push_front(&mut self, data: T) -> Result<(), T> {
+        self.insert_at(0, data)
+    }
+
+    // Removes the first element in O(1) and returns it, or None if the list is empty.
+    fn pop_front(&mut self) -> Option<T>;
+
+    // Removes the element at the specified index and returns it, or None if out of bounds.
+    fn remove_at(&mut self, index: usize) -> Option<T>;
+
+    // Removes every element.
+    fn clear(&mut self);
+}
+
+// Storage that can also be read and shrunk from the tail in O(1), which takes a doubly linked list.
+// Neither DynamicLinkedList nor StaticLinkedList qualifies, as both are singly linked;
+// XorLinkedList is the fixed size one that does.
+pub trait DequeStorage<T>: ListStorage<T> {
+    // Returns a reference to the last element, or None if the list is empty.
+    fn back(&self) -> Option<&T>;
+
+    // Removes the last element in O(1) and returns it, or None if the list is empty.
+    fn pop_back(&mut self) -> Option<T>;
+}
+
+// The standard library's doubly linked list, so adapters that need both ends have a storage to use.
+impl<T> ListStorage<T> for LinkedList<T> {
+    type Iter<'a>
+        = linked_list::Iter<'a, T>
+    where
+        T: 'a;
+
+    fn len(&self) -> usize {
+        LinkedList::len(self)
+    }
+
+    fn iter(&self) -> linked_list::Iter<'_, T> {
+        LinkedList::iter(self)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.iter_mut().nth(index)
+    }
+
+    fn push_back(&mut self, data: T) -> Result<(), T> {
+        LinkedList::push_back(self, data);
+        Ok(())
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) -> Result<(), T> {
+        if index > self.len() {
+            return Err(data);
+        }
+        let mut rest = self.split_off(index);
+        LinkedList::push_back(self, data);
+        self.append(&mut rest);
+        Ok(())
+    }
+
+    fn push_front(&mut self, data: T) -> Result<(), T> {
+        LinkedList::push_front(self, data);
+        Ok(())
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        LinkedList::pop_front(self)
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.len() {
+            return None;
+        }
+        let mut rest = self.split_off(index);
+        let data = rest.pop_front();
+        self.append(&mut rest);
+        data
+    }
+
+    fn clear(&mut self) {
+        LinkedList::clear(self);
+    }
+}
+
+impl<T> DequeStorage<T> for LinkedList<T> {
+    fn back(&self) -> Option<&T> {
+        LinkedList::back(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        LinkedList::pop_back(self)
+    }
+}
+
+// The slot arrays that index linked structures keep their StaticNodes in, with the free list
+// threaded through the unused slots as in StaticLinkedList. A fixed size array never grows;
+// a Vec grows one slot at a time, which CircularList does whenever its free list runs dry and
+// the LRU caches only when they are created or resized.
+pub trait SlotStorage<T> {
+    // Returns storage whose slots, if it has any, are all unused and chained from slot 0.
+    fn vacant() -> Self;
+
+    // Returns the slots in index order.
+    fn slots(&self) -> &[StaticNode<T>];
+
+    // Returns the slots in index order, mutably.
+    fn slots_mut(&mut self) -> &mut [StaticNode<T>];
+
+    // Adds an unused slot to the front of the free list starting at free.
+    // Returns false if the storage cannot grow.
+    fn grow(&mut self, free: &mut Option<usize>) -> bool;
+}
+
+impl<T, const N: usize> SlotStorage<T> for [StaticNode<T>; N] {
+    fn vacant() -> Self {
+        std::array::from_fn(|i| StaticNode::vacant(i, N))
+    }
+
+    fn slots(&self) -> &[StaticNode<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [StaticNode<T>] {
+        self
+    }
+
+    fn grow(&mut self, _free: &mut Option<usize>) -> bool {
+        false
+    }
+}
+
+impl<T> SlotStorage<T> for Vec<StaticNode<T>> {
+    fn vacant() -> Self {
+        Vec::new()
+    }
+
+    fn slots(&self) -> &[StaticNode<T>] {
+        self
+    }
+
+    fn slots_mut(&mut self) -> &mut [StaticNode<T>] {
+        self
+    }
+
+    fn grow(&mut self, free: &mut Option<usize>) -> bool {
+        self.push(StaticNode {
+            data: None,
+            next: free.replace(self.len()),
+        });
+        true
+    }
+}
@@ -0,0 +1,315 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+// A linked list that many threads can share, with a Mutex in every node.
+// Operations walk the list with hand-over-hand locking: the lock on the next node is taken
+// before the lock on the current one is released, and at most two locks are held at a time.
+// Threads working on different parts of the list do not block each other, and because every
+// thread takes locks in list order, from the head towards the tail, they cannot deadlock.
+// The Mutexes are not reentrant: a thread must not call into the list while it is iterating
+// over it, or it will wait on a lock it holds itself.
+pub struct SyncLinkedList<T> {
+    head: Mutex<Link<T>>,
+    len: AtomicUsize,
+}
+
+type Link<T> = Option<Box<SyncNode<T>>>;
+
+struct SyncNode<T> {
+    inner: Mutex<NodeInner<T>>,
+}
+
+struct NodeInner<T> {
+    data: T,
+    next: Link<T>,
+}
+
+// Poisoning is ignored: every structural change is made without calling user code,
+// so a panic in another thread cannot leave the links half updated.
+fn lock<U>(mutex: &Mutex<U>) -> MutexGuard<'_, U> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+// A held lock on the head link or on a node; either way it owns the next link.
+enum LinkGuard<'a, T> {
+    Head(MutexGuard<'a, Link<T>>),
+    Node(MutexGuard<'a, NodeInner<T>>),
+}
+
+impl<'a, T> LinkGuard<'a, T> {
+    fn next(&mut self) -> &mut Link<T> {
+        match self {
+            LinkGuard::Head(link) => link,
+            LinkGuard::Node(node) => &mut node.next,
+        }
+    }
+
+    // Locks the node this link points to, or returns None at the end of the list.
+    // The new lock is taken while this one is still held.
+    fn lock_next(&mut self) -> Option<MutexGuard<'a, NodeInner<T>>> {
+        let node: *const SyncNode<T> = self.next().as_deref()?;
+        // The node stays allocated for 'a: it is only freed by a thread holding both the lock
+        // on this link and the lock on the node, and this thread holds this link's lock until
+        // it owns the node's lock. Moving the Box that owns it does not move the node.
+        Some(lock(unsafe { &(*node).inner }))
+    }
+}
+
+impl<T> SyncLinkedList<T> {
+    pub fn new() -> Self {
+        SyncLinkedList {
+            head: Mutex::new(None),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    // Returns the number of elements. Other threads may change it straight away.
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Relaxed)
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    // Walks from the head until stop returns true for a node, handing over the lock on the
+    // link that points at that node, or at the end of the list if stop never returned true.
+    // stop: FnMut(usize, &T) -> bool: Called with each node's position and data.
+    fn walk<'a>(&'a self, mut stop: impl FnMut(usize, &T) -> bool) -> LinkGuard<'a, T> {
+        let mut prev = LinkGuard::Head(lock(&self.head));
+        let mut index = 0;
+        while let Some(current) = prev.lock_next() {
+            if stop(index, &current.data) {
+                return prev;
+            }
+            prev = LinkGuard::Node(current);
+            index += 1;
+        }
+        prev
+    }
+
+    // Unlinks the node after prev, which must exist, and returns its data.
+    fn unlink(&self, prev: &mut LinkGuard<'_, T>) -> T {
+        let mut current = prev.lock_next().unwrap();
+        let next = current.next.take();
+        drop(current);
+        let node = std::mem::replace(prev.next(), next).unwrap();
+        self.len.fetch_sub(1, Ordering::Relaxed);
+        node.inner
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner)
+            .data
+    }
+
+    // Links a new node holding data after prev.
+    fn link(&self, prev: &mut LinkGuard<'_, T>, data: T) {
+        let next = prev.next().take();
+        *prev.next() = Some(Box::new(SyncNode {
+            inner: Mutex::new(NodeInner { data, next }),
+        }));
+        self.len.fetch_add(1, Ordering::Relaxed);
+    }
+
+    // Inserts a new node at the end of the list. O(n), as the walk to the end takes every lock in turn.
+    // data: T: The element to insert.
+    pub fn insert(&self, data: T) {
+        let mut prev = self.walk(|_, _| false);
+        self.link(&mut prev, data);
+    }
+
+    // Inserts a new node at the specified index in the list.
+    // If the index is out of bounds, the node will not be inserted.
+    // index: usize: The position the new element will occupy.
+    // data: T: The element to insert.
+    pub fn insert_at_index(&self, index: usize, data: T) {
+        let mut visited = 0;
+        let mut prev = self.walk(|i, _| {
+            visited = i + 1;
+            i == index
+        });
+        // Either the walk stopped at index, or it ran off the end and index is the length.
+        if visited >= index {
+            self.link(&mut prev, data);
+        }
+    }
+
+    // Deletes the node at the specified index.
+    // Returns true if the node was found and deleted, false otherwise.
+    // index: usize: The position of the element to delete.
+    pub fn delete_at_index(&self, index: usize) -> bool {
+        let mut found = false;
+        let mut prev = self.walk(|i, _| {
+            found = i == index;
+            found
+        });
+        if found {
+            self.unlink(&mut prev);
+        }
+        found
+    }
+
+    // Calls f with a reference to every element, front to back, holding the lock on the
+    // element's node and no other while f runs. Safe to run alongside any other operation
+    // from other threads; f must not call into this list.
+    // f: FnMut(&T): Called once per element.
+    pub fn for_each(&self, mut f: impl FnMut(&T)) {
+        // Each temporary LinkGuard releases the lock before it at the end of its statement,
+        // once the lock after it is held, so only the current node is locked while f runs.
+        let mut current = LinkGuard::Head(lock(&self.head)).lock_next();
+        while let Some(node) = current {
+            f(&node.data);
+            current = LinkGuard::Node(node).lock_next();
+        }
+    }
+}
+
+impl<T: PartialEq> SyncLinkedList<T> {
+    // Deletes the first occurrence of the specified data from the list.
+    // Returns true if the element was found and deleted, false otherwise.
+    // data: T: The element to delete.
+    pub fn delete_element(&self, data: T) -> bool {
+        let mut found = false;
+        let mut prev = self.walk(|_, item| {
+            found = *item == data;
+            found
+        });
+        if found {
+            self.unlink(&mut prev);
+        }
+        found
+    }
+
+    // Updates the first occurrence of old_data with new_data.
+    // Returns true if the element was found and updated, false otherwise.
+    // old_data: T: The element to replace.
+    // new_data: T: The element to put in its place.
+    pub fn update_element(&self, old_data: T, new_data: T) -> bool {
+        let mut prev = self.walk(|_, item| *item == old_data);
+        match prev.lock_next() {
+            Some(mut node) => {
+                node.data = new_data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns true if the data is in the list, false otherwise.
+    // data: T: The element to look for.
+    pub fn find(&self, data: T) -> bool {
+        let mut found = false;
+        self.walk(|_, item| {
+            found = *item == data;
+            found
+        });
+        found
+    }
+}
+
+impl<T: Clone> SyncLinkedList<T> {
+    // Returns a copy of the data at the specified index, or None if it is out of bounds.
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<T> {
+        let mut prev = self.walk(|i, _| i == index);
+        prev.lock_next().map(|node| node.data.clone())
+    }
+
+    // Updates the element at the specified index with new data.
+    // Returns true if the index is valid and the element was updated, false otherwise.
+    // index: usize: The position of the element.
+    // data: T: The new element.
+    pub fn update_element_at_index(&self, index: usize, data: T) -> bool {
+        let mut prev = self.walk(|i, _| i == index);
+        match prev.lock_next() {
+            Some(mut node) => {
+                node.data = data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns an iterator over copies of the elements, front to back.
+    // Between calls to next the iterator holds the lock on the node it last returned, and moves
+    // on hand over hand, so other threads can work on the rest of the list meanwhile. Do not use
+    // the list from the same thread while the iterator is alive.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            list: self,
+            current: None,
+            done: false,
+        }
+    }
+
+    // Returns a copy of every element, front to back.
+    pub fn to_vec(&self) -> Vec<T> {
+        let mut items = Vec::with_capacity(self.len());
+        self.for_each(|data| items.push(data.clone()));
+        items
+    }
+}
+
+pub struct Iter<'a, T> {
+    list: &'a SyncLinkedList<T>,
+    current: Option<LinkGuard<'a, T>>,
+    done: bool,
+}
+
+impl<T: Clone> Iterator for Iter<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut prev = match self.current.take() {
+            Some(prev) => prev,
+            None => LinkGuard::Head(lock(&self.list.head)),
+        };
+        match prev.lock_next() {
+            Some(node) => {
+                let data = node.data.clone();
+                // Replacing prev releases its lock now that the next one is held.
+                self.current = Some(LinkGuard::Node(node));
+                Some(data)
+            }
+            None => {
+                self.done = true;
+                None
+            }
+        }
+    }
+}
+
+impl<T> Default for SyncLinkedList<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone + fmt::Debug> fmt::Debug for SyncLinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.to_vec()).finish()
+    }
+}
+
+// Unlinks the nodes one at a time so dropping a long list does not recurse.
+impl<T> Drop for SyncLinkedList<T> {
+    fn drop(&mut self) {
+        let mut link = self
+            .head
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+        while let Some(mut node) = link {
+            link = node
+                .inner
+                .get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .next
+                .take();
+        }
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod adapters_tests {
+    use std::collections::LinkedList;
+
+    use crate::adapters::{BoundedQueue, BoundedStack, Full, ListDeque, ListQueue, ListStack};
+    use crate::static_list::StaticLinkedList;
+    use crate::storage::ListStorage;
+
+    #[test]
+    fn test_queue_is_first_in_first_out() {
+        let mut queue: ListQueue<i32> = ListQueue::new();
+        assert!(queue.is_empty());
+        assert_eq!(queue.pop(), None);
+        for value in 1..=3 {
+            queue.push(value).unwrap();
+        }
+        assert_eq!(queue.len(), 3);
+        assert_eq!(queue.peek(), Some(&1));
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(4).unwrap();
+        assert_eq!(queue.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        assert_eq!(format!("{queue:?}"), "[2, 3, 4]");
+        assert_eq!(queue.pop(), Some(2));
+        assert_eq!(queue.pop(), Some(3));
+        assert_eq!(queue.pop(), Some(4));
+        assert_eq!(queue.peek(), None);
+    }
+
+    #[test]
+    fn test_stack_is_last_in_first_out() {
+        let mut stack: ListStack<i32> = ListStack::new();
+        assert_eq!(stack.pop(), None);
+        for value in 1..=3 {
+            stack.push(value).unwrap();
+        }
+        assert_eq!(stack.peek(), Some(&3));
+        assert_eq!(stack.pop(), Some(3));
+        stack.push(4).unwrap();
+        assert_eq!(stack.iter().copied().collect::<Vec<_>>(), vec![4, 2, 1]);
+        stack.clear();
+        assert!(stack.is_empty());
+    }
+
+    #[test]
+    fn test_bounded_queue_reports_full() {
+        let mut queue: BoundedQueue<i32, 2> = BoundedQueue::new();
+        queue.push(1).unwrap();
+        queue.push(2).unwrap();
+        assert_eq!(queue.push(3), Err(Full(3)));
+        assert_eq!(Full(3).to_string(), "the list is full");
+
+        // Popping frees a slot for the next push.
+        assert_eq!(queue.pop(), Some(1));
+        queue.push(3).unwrap();
+        let list: StaticLinkedList<i32, 2> = queue.into_inner();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_bounded_stack_reports_full() {
+        let mut stack: BoundedStack<&str, 1> = BoundedStack::new();
+        stack.push("a").unwrap();
+        assert_eq!(stack.push("b"), Err(Full("b")));
+        assert_eq!(stack.pop(), Some("a"));
+        stack.push("b").unwrap();
+        assert_eq!(stack.peek(), Some(&"b"));
+    }
+
+    #[test]
+    fn test_deque_both_ends() {
+        let mut deque: ListDeque<i32> = ListDeque::new();
+        deque.push_back(2).unwrap();
+        deque.push_front(1).unwrap();
+        deque.push_back(3).unwrap();
+        assert_eq!(deque.len(), 3);
+        assert_eq!(deque.peek_front(), Some(&1));
+        assert_eq!(deque.peek_back(), Some(&3));
+        assert_eq!(format!("{deque:?}"), "[1, 2, 3]");
+
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.pop_back(), Some(2));
+        assert_eq!(deque.pop_front(), None);
+        assert_eq!(deque.pop_back(), None);
+        assert!(deque.is_empty());
+    }
+
+    // The LinkedList storage behind ListDeque also has to behave like any other ListStorage.
+    #[test]
+    fn test_linked_list_storage() {
+        let mut list = LinkedList::new();
+        ListStorage::push_back(&mut list, 1).unwrap();
+        ListStorage::push_back(&mut list, 3).unwrap();
+        list.insert_at(1, 2).unwrap();
+        list.insert_at(3, 4).unwrap();
+        assert_eq!(list.insert_at(9, 9), Err(9));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        *list.get_mut(0).unwrap() = 10;
+        assert_eq!(list.remove_at(1), Some(2));
+        assert_eq!(list.remove_at(3), None);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![10, 3, 4]);
+    }
+}
@@ -0,0 +1,122 @@
+#[cfg(test)]
+mod atomic_pool_tests {
+    use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    use crate::atomic_pool::AtomicSlotPool;
+
+    const THREADS: usize = 8;
+    const OPS_PER_THREAD: usize = 20_000;
+
+    #[test]
+    fn test_acquire_and_release() {
+        let pool: AtomicSlotPool<String, 3> = AtomicSlotPool::new();
+        assert!(pool.is_empty());
+        assert_eq!(pool.capacity(), 3);
+
+        let a = pool.acquire("a".to_string()).unwrap();
+        let mut b = pool.acquire("b".to_string()).unwrap();
+        let c = pool.acquire("c".to_string()).unwrap();
+        assert_eq!(pool.len(), 3);
+        assert_eq!(pool.acquire("d".to_string()).unwrap_err(), "d");
+
+        b.push('!');
+        assert_eq!(*b, "b!");
+        assert_eq!((a.index(), b.index(), c.index()), (0, 1, 2));
+
+        // Released slots are reused most recently released first.
+        let b_index = b.index();
+        assert_eq!(b.into_inner(), "b!");
+        drop(a);
+        assert_eq!(pool.len(), 1);
+        let e = pool.acquire("e".to_string()).unwrap();
+        let f = pool.acquire("f".to_string()).unwrap();
+        assert_eq!((e.index(), f.index()), (0, b_index));
+        assert_eq!(format!("{e:?}"), r#"PoolSlot { index: 0, data: "e" }"#);
+        assert_eq!(
+            format!("{pool:?}"),
+            "AtomicSlotPool { capacity: 3, len: 3 }"
+        );
+    }
+
+    #[test]
+    fn test_zero_capacity() {
+        let pool: AtomicSlotPool<i32, 0> = AtomicSlotPool::new();
+        assert_eq!(pool.acquire(1).unwrap_err(), 1);
+    }
+
+    // Threads outnumber the slots, so the free list is under constant contention and keeps
+    // running dry. Each slot has an owner flag that a thread sets when it is handed the slot
+    // and clears before giving it back; finding it already set means the slot was handed out twice.
+    #[test]
+    fn test_no_slot_handed_out_twice() {
+        const SLOTS: usize = 4;
+        let pool: AtomicSlotPool<(usize, usize), SLOTS> = AtomicSlotPool::new();
+        let owned: Vec<AtomicBool> = (0..SLOTS).map(|_| AtomicBool::new(false)).collect();
+        let barrier = Barrier::new(THREADS);
+
+        thread::scope(|scope| {
+            for t in 0..THREADS {
+                let (pool, owned, barrier) = (&pool, &owned, &barrier);
+                scope.spawn(move || {
+                    barrier.wait();
+                    let mut held = Vec::new();
+                    for i in 0..OPS_PER_THREAD {
+                        // Hold up to two slots at a time so slots are released out of order.
+                        if let Ok(slot) = pool.acquire((t, i)) {
+                            assert!(
+                                !owned[slot.index()].swap(true, Ordering::SeqCst),
+                                "slot {} handed out twice",
+                                slot.index()
+                            );
+                            held.push(slot);
+                        }
+                        if held.len() == 2 || (i % 3 == 0 && !held.is_empty()) {
+                            let slot = held.remove(i % held.len());
+                            assert_eq!(slot.0, t);
+                            owned[slot.index()].store(false, Ordering::SeqCst);
+                            drop(slot);
+                        }
+                    }
+                    for slot in held {
+                        owned[slot.index()].store(false, Ordering::SeqCst);
+                    }
+                });
+            }
+        });
+
+        assert!(pool.is_empty());
+        let all: Vec<_> = (0..SLOTS).map(|i| pool.acquire((0, i)).unwrap()).collect();
+        let mut indices: Vec<_> = all.iter().map(|slot| slot.index()).collect();
+        indices.sort_unstable();
+        assert_eq!(indices, (0..SLOTS).collect::<Vec<_>>());
+    }
+
+    // An element that counts how many times it has been dropped.
+    #[derive(Debug)]
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_data_dropped_once() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let pool: AtomicSlotPool<Tracked, 2> = AtomicSlotPool::new();
+
+        let slot = pool.acquire(Tracked(drops.clone())).unwrap();
+        drop(slot);
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+
+        let slot = pool.acquire(Tracked(drops.clone())).unwrap();
+        let data = slot.into_inner();
+        assert_eq!(drops.load(Ordering::Relaxed), 1);
+        drop(data);
+        assert_eq!(drops.load(Ordering::Relaxed), 2);
+        assert!(pool.is_empty());
+    }
+}
@@ -0,0 +1,125 @@
+#[cfg(test)]
+mod circular_tests {
+    use crate::circular_list::{CircularList, StaticCircularList};
+
+    #[test]
+    fn test_empty_list() {
+        let mut list: CircularList<i32> = CircularList::new();
+        assert!(list.is_empty());
+        assert_eq!(list.current(), None);
+        list.advance();
+        list.advance_by(3);
+        assert_eq!(list.remove_current(), None);
+        assert_eq!(list.cycle_iter().next(), None);
+        assert_eq!(list.iter().next(), None);
+    }
+
+    #[test]
+    fn test_advance_wraps_round() {
+        let mut list: CircularList<&str> = ["a", "b", "c"].into_iter().collect();
+        assert_eq!(list.current(), Some(&"a"));
+        list.advance();
+        assert_eq!(list.current(), Some(&"b"));
+        list.advance();
+        list.advance();
+        assert_eq!(list.current(), Some(&"a"));
+        list.advance_by(5);
+        assert_eq!(list.current(), Some(&"c"));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec!["c", "a", "b"]
+        );
+        assert_eq!(format!("{list:?}"), r#"["c", "a", "b"]"#);
+    }
+
+    #[test]
+    fn test_insert_around_current() {
+        let mut list: CircularList<i32> = CircularList::new();
+        list.insert_after_current(1).unwrap();
+        assert_eq!(list.current(), Some(&1));
+        list.insert_after_current(3).unwrap();
+        list.insert_after_current(2).unwrap();
+        list.insert_before_current(4).unwrap();
+        assert_eq!(list.current(), Some(&1));
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        *list.current_mut().unwrap() = 10;
+        list.advance_by(3);
+        assert_eq!(list.current(), Some(&4));
+        list.advance();
+        assert_eq!(list.current(), Some(&10));
+    }
+
+    #[test]
+    fn test_remove_current_moves_to_next() {
+        let mut list: CircularList<i32> = (1..=4).collect();
+        list.advance_by(3);
+        assert_eq!(list.remove_current(), Some(4));
+        assert_eq!(list.current(), Some(&1));
+        list.advance();
+        assert_eq!(list.remove_current(), Some(2));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 1]);
+        assert_eq!(list.remove_current(), Some(3));
+        assert_eq!(list.remove_current(), Some(1));
+        assert!(list.is_empty());
+        assert_eq!(list.current(), None);
+
+        // The list is usable again once emptied.
+        list.insert_before_current(5).unwrap();
+        assert_eq!(
+            list.cycle_iter().take(3).copied().collect::<Vec<_>>(),
+            vec![5, 5, 5]
+        );
+    }
+
+    // Round-robin scheduling: hand out work in turn while workers join and leave.
+    #[test]
+    fn test_round_robin() {
+        let mut workers: CircularList<&str> = ["w1", "w2", "w3"].into_iter().collect();
+        let mut order = Vec::new();
+        for task in 0..7 {
+            if task == 3 {
+                // w1 is current again after a full lap and leaves.
+                assert_eq!(workers.remove_current(), Some("w1"));
+            }
+            if task == 5 {
+                workers.insert_before_current("w4").unwrap();
+            }
+            order.push(*workers.current().unwrap());
+            workers.advance();
+        }
+        assert_eq!(order, vec!["w1", "w2", "w3", "w2", "w3", "w2", "w3"]);
+        assert_eq!(
+            workers.cycle_iter().take(6).copied().collect::<Vec<_>>(),
+            vec!["w4", "w2", "w3", "w4", "w2", "w3"]
+        );
+    }
+
+    #[test]
+    fn test_static_list_reuses_slots() {
+        let mut list: StaticCircularList<i32, 3> = StaticCircularList::new();
+        for value in 1..=3 {
+            list.insert_before_current(value).unwrap();
+        }
+        assert_eq!(list.insert_after_current(4), Err(4));
+        assert_eq!(list.insert_before_current(4), Err(4));
+
+        assert_eq!(list.remove_current(), Some(1));
+        list.insert_before_current(4).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+        list.clear();
+        assert!(list.is_empty());
+        for value in 5..=7 {
+            list.insert_after_current(value).unwrap();
+        }
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![5, 7, 6]);
+    }
+
+    #[test]
+    fn test_static_zero_capacity() {
+        let mut list: StaticCircularList<i32, 0> = StaticCircularList::new();
+        assert_eq!(list.insert_after_current(1), Err(1));
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,195 @@
+#[cfg(test)]
+mod concurrent_tests {
+    use std::collections::BTreeSet;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::{Arc, Barrier};
+    use std::thread;
+
+    use crate::concurrent_list::ConcurrentList;
+    use crate::tests::Rng;
+
+    const THREADS: usize = 8;
+    const OPS_PER_THREAD: usize = 20_000;
+
+    // Runs body on THREADS threads released together, passing each its index.
+    // Returns what each thread returned, in thread order.
+    fn run_threads<R: Send, F: Fn(usize) -> R + Sync>(body: F) -> Vec<R> {
+        let barrier = Barrier::new(THREADS);
+        thread::scope(|scope| {
+            let handles: Vec<_> = (0..THREADS)
+                .map(|t| {
+                    let (barrier, body) = (&barrier, &body);
+                    scope.spawn(move || {
+                        barrier.wait();
+                        body(t)
+                    })
+                })
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        })
+    }
+
+    #[test]
+    fn test_sequential_operations() {
+        let list = ConcurrentList::new();
+        assert!(list.is_empty());
+        for value in [5, 1, 9, 3, 7] {
+            assert!(list.insert(value));
+        }
+        assert!(!list.insert(3));
+        assert_eq!(list.to_vec(), vec![1, 3, 5, 7, 9]);
+        assert!(list.contains(&7));
+        assert!(!list.contains(&4));
+
+        assert!(list.delete(&1));
+        assert!(list.delete(&9));
+        assert!(!list.delete(&9));
+        assert!(!list.contains(&9));
+        assert_eq!(list.len(), 3);
+        assert_eq!(format!("{list:?}"), "[3, 5, 7]");
+    }
+
+    // Each thread owns the keys congruent to its index, so its results must match a
+    // sequential model exactly, while the nodes it touches are interleaved with everyone else's.
+    #[test]
+    fn test_stress_matches_sequential_model() {
+        let list = ConcurrentList::new();
+        let models = run_threads(|t| {
+            let mut model = BTreeSet::new();
+            let mut rng = Rng::new(0x9E37_79B9 + t as u64);
+            for _ in 0..OPS_PER_THREAD {
+                let key = rng.below(32) * THREADS as u64 + t as u64;
+                match rng.below(3) {
+                    0 => assert_eq!(list.insert(key), model.insert(key)),
+                    1 => assert_eq!(list.delete(&key), model.remove(&key)),
+                    _ => assert_eq!(list.contains(&key), model.contains(&key)),
+                }
+            }
+            model
+        });
+
+        let expected: Vec<u64> = models
+            .into_iter()
+            .flatten()
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+        assert_eq!(list.to_vec(), expected);
+        assert_eq!(list.len(), expected.len());
+    }
+
+    // Every thread works on the same few keys. For each key, successful inserts and deletes
+    // must alternate, so at the end they differ by one exactly when the key is present.
+    #[test]
+    fn test_stress_shared_keys_stay_consistent() {
+        const KEYS: usize = 16;
+        let list = ConcurrentList::new();
+        let inserted: Vec<AtomicUsize> = (0..KEYS).map(|_| AtomicUsize::new(0)).collect();
+        let deleted: Vec<AtomicUsize> = (0..KEYS).map(|_| AtomicUsize::new(0)).collect();
+
+        run_threads(|t| {
+            let mut rng = Rng::new(0xDEAD_BEEF + t as u64);
+            for _ in 0..OPS_PER_THREAD {
+                let key = rng.below(KEYS as u64) as usize;
+                if rng.next_u64().is_multiple_of(2) {
+                    if list.insert(key) {
+                        inserted[key].fetch_add(1, Ordering::Relaxed);
+                    }
+                } else if list.delete(&key) {
+                    deleted[key].fetch_add(1, Ordering::Relaxed);
+                }
+            }
+        });
+
+        let contents = list.to_vec();
+        assert!(contents.windows(2).all(|pair| pair[0] < pair[1]));
+        for key in 0..KEYS {
+            let balance =
+                inserted[key].load(Ordering::Relaxed) - deleted[key].load(Ordering::Relaxed);
+            assert_eq!(balance, contents.contains(&key) as usize, "key {key}");
+        }
+    }
+
+    // An element that counts how many times it has been dropped.
+    struct Tracked {
+        key: u64,
+        drops: Arc<AtomicUsize>,
+    }
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.drops.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    impl PartialEq for Tracked {
+        fn eq(&self, other: &Self) -> bool {
+            self.key == other.key
+        }
+    }
+
+    impl Eq for Tracked {}
+
+    impl PartialOrd for Tracked {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Tracked {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.key.cmp(&other.key)
+        }
+    }
+
+    #[test]
+    fn test_deleted_nodes_are_reclaimed() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let tracked = |key| Tracked {
+            key,
+            drops: drops.clone(),
+        };
+        let list = ConcurrentList::new();
+        for key in 0..1_000 {
+            list.insert(tracked(key));
+        }
+        for key in 0..1_000 {
+            assert!(list.delete(&tracked(key)));
+        }
+        // One probe is dropped per delete; most deleted nodes are freed while the list is alive.
+        let probes = 1_000;
+        assert!(drops.load(Ordering::Relaxed) - probes >= 900);
+
+        drop(list);
+        assert_eq!(drops.load(Ordering::Relaxed), 2_000);
+    }
+
+    #[test]
+    fn test_every_element_dropped_once_under_contention() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let created = AtomicUsize::new(0);
+        let list = ConcurrentList::new();
+
+        run_threads(|t| {
+            let mut rng = Rng::new(0x1234_5678 + t as u64);
+            for _ in 0..OPS_PER_THREAD / 4 {
+                let element = Tracked {
+                    key: rng.below(64),
+                    drops: drops.clone(),
+                };
+                created.fetch_add(1, Ordering::Relaxed);
+                if rng.next_u64().is_multiple_of(2) {
+                    list.insert(element);
+                } else {
+                    list.delete(&element);
+                }
+            }
+        });
+
+        drop(list);
+        assert_eq!(
+            drops.load(Ordering::Relaxed),
+            created.load(Ordering::Relaxed)
+        );
+    }
+}
@@ -0,0 +1,39 @@
+#[cfg(test)]
+mod dot_tests {
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::static_list::StaticLinkedList;
+
+    #[test]
+    fn test_dynamic_to_dot_matches_golden_file() {
+        let mut list = DynamicLinkedList::new();
+        list.insert("a");
+        list.insert("b \"quoted\"");
+        list.insert("c");
+
+        assert_eq!(list.to_dot(), include_str!("golden/dynamic_list.dot"));
+    }
+
+    #[test]
+    fn test_static_to_dot_matches_golden_file() {
+        let mut list = StaticLinkedList::<i32, 5>::new();
+        list.insert(10);
+        list.insert(20);
+        list.insert(30);
+        list.insert_at_index(0, 5);
+        list.delete_element(20); // slot 1 goes back to the free list
+
+        assert_eq!(list.to_dot(), include_str!("golden/static_list.dot"));
+    }
+
+    #[test]
+    fn test_empty_lists_to_dot() {
+        let dynamic: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        assert!(!dynamic.to_dot().contains("->"));
+
+        let list = StaticLinkedList::<i32, 2>::new();
+        let dot = list.to_dot();
+        assert!(dot.contains("free -> s0 [style=dashed];"));
+        assert!(!dot.contains("head ->"));
+        assert!(!dot.contains("color=red"));
+    }
+}
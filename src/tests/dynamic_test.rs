@@ -23,6 +23,19 @@ mod dynamic_tests {
         assert_eq!(list.get(3), None); // Out of bounds
     }
 
+    #[test]
+    fn test_head_walks_the_chain() {
+        let mut list = DynamicLinkedList::new();
+        assert!(list.head().is_none());
+        list.insert(10);
+        list.insert(20);
+
+        let head = list.head().unwrap();
+        assert_eq!(head.data, 10);
+        assert_eq!(head.next.as_ref().unwrap().data, 20);
+        assert!(head.next.as_ref().unwrap().next.is_none());
+    }
+
     #[test]
     fn test_insert_at_index() {
         let mut list = DynamicLinkedList::new();
@@ -114,5 +127,328 @@ mod dynamic_tests {
         assert!(!list.find(999)); // Does not exist
     }
 
+    #[test]
+    fn test_append() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        list.insert(2);
+        let mut other = DynamicLinkedList::new();
+        other.insert(3);
+        other.insert(4);
+
+        list.append(&mut other);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(list.len(), 4);
+        assert!(other.is_empty());
+
+        list.insert(5); // tail must point at the appended nodes
+        assert_eq!(list.get(4), Some(5));
+
+        let mut empty = DynamicLinkedList::new();
+        empty.append(&mut list);
+        assert_eq!(empty.len(), 5);
+        assert!(list.is_empty());
+    }
+
+    #[test]
+    fn test_split_off() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..5 {
+            list.insert(i);
+        }
+
+        let rest = list.split_off(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
 
-}
\ No newline at end of file
+        list.insert(9); // tail must be updated to the split point
+        assert_eq!(list.get(2), Some(9));
+
+        assert!(list.split_off(10).is_empty()); // out of bounds splits off nothing
+        assert_eq!(list.len(), 3);
+    }
+
+    #[test]
+    fn test_splice_at() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        list.insert(4);
+        let mut other = DynamicLinkedList::new();
+        other.insert(2);
+        other.insert(3);
+
+        assert!(list.splice_at(1, &mut other));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert!(other.is_empty());
+
+        let mut front = DynamicLinkedList::new();
+        front.insert(0);
+        assert!(list.splice_at(0, &mut front));
+        assert_eq!(list.get(0), Some(0));
+
+        let mut bad = DynamicLinkedList::new();
+        bad.insert(99);
+        assert!(!list.splice_at(10, &mut bad)); // out of bounds, other untouched
+        assert_eq!(bad.len(), 1);
+        assert_eq!(list.len(), 5);
+    }
+
+    #[test]
+    fn test_truncate_and_clear() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..5 {
+            list.insert(i);
+        }
+
+        list.truncate(10); // no effect
+        assert_eq!(list.len(), 5);
+        list.truncate(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+        list.insert(7);
+        assert_eq!(list.get(2), Some(7));
+
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.get(0), None);
+        list.insert(1);
+        assert_eq!(list.get(0), Some(1));
+    }
+
+    #[test]
+    fn test_delete_last_updates_tail() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        list.insert(2);
+
+        assert!(list.delete_at_index(1));
+        list.insert(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 3]);
+
+        assert!(list.delete_element(3));
+        assert!(list.delete_element(1));
+        list.insert(4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4]);
+    }
+
+    #[test]
+    #[cfg_attr(
+        feature = "debug-invariants",
+        ignore = "validating after every push makes this quadratic"
+    )]
+    fn test_drop_long_list() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..200_000 {
+            list.insert(i);
+        }
+        drop(list); // must not overflow the stack
+    }
+
+    #[test]
+    fn test_sort() {
+        let mut list = DynamicLinkedList::new();
+        for value in [5, 3, 9, 1, 4, 8, 2, 7, 6] {
+            list.insert(value);
+        }
+        assert!(!list.is_sorted());
+
+        list.sort();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (1..=9).collect::<Vec<_>>());
+        assert!(list.is_sorted());
+
+        list.insert(10); // tail must point at the new last node
+        assert_eq!(list.get(9), Some(10));
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.get(0), Some(10));
+        assert_eq!(list.get(9), Some(1));
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable() {
+        let mut list = DynamicLinkedList::new();
+        for pair in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')] {
+            list.insert(pair);
+        }
+
+        list.sort_by_key(|pair| pair.0);
+        assert_eq!(
+            list.iter().map(|pair| pair.1).collect::<String>(),
+            "ebdac"
+        );
+    }
+
+    #[test]
+    fn test_sort_short_lists() {
+        let mut empty: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        empty.sort();
+        assert!(empty.is_sorted());
+
+        let mut list = DynamicLinkedList::new();
+        list.insert(2);
+        list.sort();
+        list.insert(1);
+        list.sort();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_sort_matches_vec() {
+        for size in 0..40 {
+            let mut list = DynamicLinkedList::new();
+            let mut expected = Vec::new();
+            for i in 0..size {
+                let value = (i * 7919) % 13;
+                list.insert(value);
+                expected.push(value);
+            }
+
+            list.sort();
+            expected.sort();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    // A comparator that panics partway through must leave every node in the list,
+    // with a tail pointer and length that still match it.
+    #[test]
+    fn test_sort_by_panic_keeps_list_whole() {
+        let mut list = DynamicLinkedList::new();
+        for value in [5, 3, 9, 1, 4, 8, 2, 7, 6] {
+            list.insert(value);
+        }
+
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                if calls == 10 {
+                    panic!("comparator failed");
+                }
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 9);
+        assert!(list.validate().is_ok());
+
+        list.insert(42);
+        assert_eq!(list.get(9), Some(42));
+        let mut values = list.iter().copied().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 42]);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let mut list = DynamicLinkedList::new();
+        for i in 1..=4 {
+            list.insert(i);
+        }
+
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        list.insert(0); // tail is the old head
+        assert_eq!(list.get(4), Some(0));
+
+        let mut empty: DynamicLinkedList<i32> = DynamicLinkedList::new();
+        empty.reverse();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_rotate() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..5 {
+            list.insert(i);
+        }
+
+        list.rotate_left(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+        list.rotate_right(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        list.rotate_left(7); // same as rotating by 2
+        assert_eq!(list.get(0), Some(2));
+        list.insert(5);
+        assert_eq!(list.get(5), Some(5));
+    }
+
+    #[test]
+    fn test_dedup() {
+        let mut list = DynamicLinkedList::new();
+        for value in [1, 1, 2, 3, 3, 3, 1, 4, 4] {
+            list.insert(value);
+        }
+
+        list.dedup();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 1, 4]);
+        assert_eq!(list.len(), 5);
+        list.insert(9); // tail survives removal of the old last node
+        assert_eq!(list.get(5), Some(9));
+
+        let mut words = DynamicLinkedList::new();
+        for word in ["apple", "avocado", "banana", "blueberry", "cherry"] {
+            words.insert(word);
+        }
+        words.dedup_by_key(|word| word.chars().next());
+        assert_eq!(words.iter().copied().collect::<Vec<_>>(), vec!["apple", "banana", "cherry"]);
+    }
+
+    // A same_bucket that panics keeps the duplicates already removed and the rest of the
+    // list untouched, with a tail pointer and length that still match it.
+    #[test]
+    fn test_dedup_by_panic_keeps_list_whole() {
+        let mut list = DynamicLinkedList::new();
+        for value in [1, 1, 2, 3, 3, 4] {
+            list.insert(value);
+        }
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.dedup_by(|a, b| {
+                if *a == 3 {
+                    panic!("same_bucket failed");
+                }
+                a == b
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 3, 4]);
+        assert_eq!(list.len(), 5);
+        assert!(list.validate().is_ok());
+
+        list.insert(42);
+        assert_eq!(list.get(5), Some(42));
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..4 {
+            list.insert(i);
+        }
+
+        assert!(list.swap(0, 3));
+        assert!(list.swap(2, 1));
+        assert!(list.swap(1, 1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert!(!list.swap(0, 4)); // out of bounds
+    }
+
+    #[test]
+    fn test_nth_from_back_and_middle() {
+        let mut list = DynamicLinkedList::new();
+        assert_eq!(list.nth_from_back(0), None);
+        assert_eq!(list.middle(), None);
+
+        for i in 0..5 {
+            list.insert(i);
+        }
+        assert_eq!(list.nth_from_back(0), Some(&4));
+        assert_eq!(list.nth_from_back(4), Some(&0));
+        assert_eq!(list.nth_from_back(5), None);
+        assert_eq!(list.middle(), Some(&2));
+
+        list.insert(5);
+        assert_eq!(list.middle(), Some(&3)); // second of the two middle elements
+        assert!(!list.has_cycle());
+    }
+}
@@ -0,0 +1,154 @@
+#[cfg(test)]
+mod intrusive_tests {
+    use std::cell::Cell;
+    use std::pin::pin;
+
+    use crate::intrusive::{IntrusiveList, Link};
+
+    // A task that sits in a run queue and, at the same time, in its owner's list of tasks.
+    #[derive(Debug)]
+    struct Task {
+        id: u32,
+        runs: Cell<u32>,
+        queue_link: Link,
+        owner_link: Link,
+    }
+
+    impl Task {
+        fn new(id: u32) -> Self {
+            Task {
+                id,
+                runs: Cell::new(0),
+                queue_link: Link::new(),
+                owner_link: Link::new(),
+            }
+        }
+    }
+
+    // Task holds Links, so it is not Unpin, and nothing here implements Unpin for it.
+    crate::intrusive_adapter!(unsafe ByQueue = Task { queue_link });
+    crate::intrusive_adapter!(unsafe ByOwner = Task { owner_link });
+
+    fn ids(list: &IntrusiveList<'_, impl crate::intrusive::Adapter<Value = Task>>) -> Vec<u32> {
+        list.iter().map(|task| task.id).collect()
+    }
+
+    #[test]
+    fn test_push_pop_and_iterate() {
+        let (a, b, c) = (pin!(Task::new(1)), pin!(Task::new(2)), pin!(Task::new(3)));
+        let mut queue: IntrusiveList<ByQueue> = IntrusiveList::new();
+        assert!(queue.is_empty());
+        assert!(queue.pop_front().is_none());
+        queue.push_back(b.as_ref());
+        queue.push_front(a.as_ref());
+        queue.push_back(c.as_ref());
+        assert_eq!(queue.len(), 3);
+        assert_eq!(ids(&queue), vec![1, 2, 3]);
+        assert_eq!(
+            queue.iter().rev().map(|task| task.id).collect::<Vec<_>>(),
+            vec![3, 2, 1]
+        );
+        assert_eq!(
+            (queue.front().unwrap().id, queue.back().unwrap().id),
+            (1, 3)
+        );
+
+        // Round robin: run the task at the front and put it back at the end.
+        for _ in 0..4 {
+            let task = queue.pop_front().unwrap();
+            task.runs.set(task.runs.get() + 1);
+            queue.push_back(task);
+        }
+        assert_eq!(ids(&queue), vec![2, 3, 1]);
+        assert_eq!(a.runs.get(), 2);
+
+        assert_eq!(queue.pop_back().unwrap().id, 1);
+        assert!(!a.queue_link.is_linked());
+        queue.clear();
+        assert!(queue.is_empty());
+        assert!(!b.queue_link.is_linked());
+    }
+
+    #[test]
+    fn test_object_in_two_lists() {
+        let tasks = [
+            pin!(Task::new(1)),
+            pin!(Task::new(2)),
+            pin!(Task::new(3)),
+            pin!(Task::new(4)),
+        ];
+        let mut queue: IntrusiveList<ByQueue> = IntrusiveList::new();
+        let mut alice: IntrusiveList<ByOwner> = IntrusiveList::new();
+        let mut bob: IntrusiveList<ByOwner> = IntrusiveList::new();
+        for task in &tasks {
+            queue.push_front(task.as_ref());
+            if task.id % 2 == 0 {
+                alice.push_back(task.as_ref());
+            } else {
+                bob.push_back(task.as_ref());
+            }
+        }
+        assert_eq!(ids(&queue), vec![4, 3, 2, 1]);
+        assert_eq!(ids(&alice), vec![2, 4]);
+        assert_eq!(ids(&bob), vec![1, 3]);
+
+        // Taking task 3 off the queue leaves it with its owner, and the other way round.
+        assert!(queue.remove(&tasks[2]));
+        assert!(!queue.contains(&tasks[2]));
+        assert!(bob.contains(&tasks[2]));
+        assert!(alice.remove(&tasks[3]));
+        assert!(queue.contains(&tasks[3]));
+        assert_eq!(ids(&queue), vec![4, 2, 1]);
+        assert_eq!(ids(&alice), vec![2]);
+        assert_eq!(ids(&bob), vec![1, 3]);
+
+        // A value in one owner list is not in the other, so removing it there does nothing.
+        assert!(!alice.remove(&tasks[0]));
+        assert!(bob.contains(&tasks[0]));
+    }
+
+    #[test]
+    #[should_panic(expected = "already in a list")]
+    fn test_linking_twice_panics() {
+        let task = pin!(Task::new(1));
+        let mut first: IntrusiveList<ByQueue> = IntrusiveList::new();
+        let mut second: IntrusiveList<ByQueue> = IntrusiveList::new();
+        first.push_back(task.as_ref());
+        second.push_back(task.as_ref());
+    }
+
+    // Values dropped before their list unlink themselves, and the list's own drop unlinks
+    // whatever is left, so values that outlive it can be linked again.
+    #[test]
+    fn test_drop_unlinks() {
+        let survivor = pin!(Task::new(1));
+        {
+            let mut queue: IntrusiveList<ByQueue> = IntrusiveList::new();
+            let short_lived = pin!(Task::new(2));
+            let boxed = Box::pin(Task::new(3));
+            queue.push_back(survivor.as_ref());
+            queue.push_back(short_lived.as_ref());
+            queue.push_back(boxed.as_ref());
+            assert_eq!(ids(&queue), vec![1, 2, 3]);
+            drop(boxed);
+            // short_lived goes next, then queue, each unlinking as it is dropped.
+        }
+        assert!(!survivor.queue_link.is_linked());
+
+        let mut queue: IntrusiveList<ByQueue> = IntrusiveList::new();
+        queue.push_back(survivor.as_ref());
+        assert_eq!(ids(&queue), vec![1]);
+    }
+
+    #[test]
+    fn test_list_can_move_while_linked() {
+        let (a, b) = (pin!(Task::new(1)), pin!(Task::new(2)));
+        let mut queue: IntrusiveList<ByQueue> = IntrusiveList::new();
+        queue.push_back(a.as_ref());
+        let mut lists = vec![queue];
+        lists[0].push_back(b.as_ref());
+        let moved = lists.pop().unwrap();
+        assert_eq!(ids(&moved), vec![1, 2]);
+        assert!(moved.contains(&a));
+    }
+}
@@ -0,0 +1,180 @@
+#[cfg(test)]
+mod invariants_tests {
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::invariants::{Chain, InvariantViolation};
+    use crate::static_list::StaticLinkedList;
+
+    // Slots 0 -> 2 are live, 1 -> 3 are free.
+    fn layout() -> [(Option<i32>, Option<usize>); 4] {
+        [
+            (Some(1), Some(2)),
+            (None, Some(3)),
+            (Some(2), None),
+            (None, None),
+        ]
+    }
+
+    fn rebuild(
+        slots: [(Option<i32>, Option<usize>); 4],
+        head: Option<usize>,
+        free: Option<usize>,
+    ) -> Result<StaticLinkedList<i32, 4>, InvariantViolation> {
+        StaticLinkedList::from_parts(slots, head, free)
+    }
+
+    #[test]
+    fn test_valid_lists_pass() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        assert_eq!(list.validate(), Ok(()));
+        list.insert(1);
+        list.insert(2);
+        list.insert(3);
+        list.delete_at_index(1);
+        assert_eq!(list.validate(), Ok(()));
+
+        let list = rebuild(layout(), Some(0), Some(1)).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(list.validate(), Ok(()));
+
+        let mut list = DynamicLinkedList::new();
+        assert_eq!(list.validate(), Ok(()));
+        for i in 0..5 {
+            list.insert(i);
+        }
+        list.reverse();
+        assert_eq!(list.validate(), Ok(()));
+    }
+
+    #[test]
+    fn test_static_broken_chains() {
+        let mut slots = layout();
+        slots[2].1 = Some(9);
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::IndexOutOfRange {
+                chain: Chain::Live,
+                index: 9
+            })
+        );
+
+        let mut slots = layout();
+        slots[2].1 = Some(0);
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::Cycle {
+                chain: Chain::Live,
+                slot: 0
+            })
+        );
+
+        let mut slots = layout();
+        slots[3].1 = Some(1);
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::Cycle {
+                chain: Chain::Free,
+                slot: 1
+            })
+        );
+
+        let mut slots = layout();
+        slots[3].1 = Some(2);
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::SharedSlot { slot: 2 })
+        );
+    }
+
+    #[test]
+    fn test_static_bad_slots() {
+        let mut slots = layout();
+        slots[2].0 = None;
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::EmptyLiveSlot { slot: 2 })
+        );
+
+        let mut slots = layout();
+        slots[3].0 = Some(7);
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::OccupiedFreeSlot { slot: 3 })
+        );
+
+        // Slot 3 drops off the free chain and is lost.
+        let mut slots = layout();
+        slots[1].1 = None;
+        assert_eq!(
+            rebuild(slots, Some(0), Some(1)).err(),
+            Some(InvariantViolation::OrphanedSlot { slot: 3 })
+        );
+    }
+
+    #[test]
+    fn test_static_bookkeeping() {
+        let mut list = rebuild(layout(), Some(0), Some(1)).unwrap();
+        list.corrupt_len(3);
+        assert_eq!(
+            list.validate(),
+            Err(InvariantViolation::LengthMismatch {
+                cached: 3,
+                actual: 2
+            })
+        );
+
+        let mut list = rebuild(layout(), Some(0), Some(1)).unwrap();
+        list.corrupt_tail(Some(0));
+        assert_eq!(
+            list.validate(),
+            Err(InvariantViolation::TailMismatch {
+                tail: Some(0),
+                last: Some(2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_dynamic_bookkeeping() {
+        let mut list = DynamicLinkedList::new();
+        for i in 0..3 {
+            list.insert(i);
+        }
+
+        list.corrupt_len(5);
+        assert_eq!(
+            list.validate(),
+            Err(InvariantViolation::LengthMismatch {
+                cached: 5,
+                actual: 3
+            })
+        );
+        list.corrupt_len(3);
+
+        list.corrupt_tail_to_head();
+        assert_eq!(
+            list.validate(),
+            Err(InvariantViolation::TailMismatch {
+                tail: Some(0),
+                last: Some(2)
+            })
+        );
+    }
+
+    #[test]
+    fn test_violation_message() {
+        let violation = InvariantViolation::Cycle {
+            chain: Chain::Free,
+            slot: 4,
+        };
+        assert_eq!(violation.to_string(), "free chain loops back to slot 4");
+    }
+
+    #[cfg(feature = "debug-invariants")]
+    #[test]
+    #[should_panic(expected = "StaticLinkedList invariant violated")]
+    fn test_debug_invariants_panics_on_mutation() {
+        let mut list = rebuild(layout(), Some(0), Some(1)).unwrap();
+        list.corrupt_len(7);
+        list.insert(3);
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod lru_tests {
+    use std::sync::{Arc, Mutex};
+
+    use crate::lru_cache::{HeapLruCache, LruCache};
+
+    // Returns the keys from the most recently used to the least.
+    fn keys<K, V, I: Iterator<Item = (K, V)>>(iter: I) -> Vec<K> {
+        iter.map(|(key, _)| key).collect()
+    }
+
+    #[test]
+    fn test_put_and_get() {
+        let mut cache: LruCache<&str, i32, 3> = LruCache::new();
+        assert!(cache.is_empty());
+        assert_eq!(cache.capacity(), 3);
+
+        assert_eq!(cache.put("a", 1), None);
+        assert_eq!(cache.put("b", 2), None);
+        assert_eq!(cache.put("c", 3), None);
+        assert!(cache.is_full());
+        assert_eq!(keys(cache.iter()), vec![&"c", &"b", &"a"]);
+
+        // get promotes, peek does not.
+        assert_eq!(cache.get("a"), Some(&1));
+        assert_eq!(cache.peek("b"), Some(&2));
+        assert_eq!(keys(cache.iter()), vec![&"a", &"c", &"b"]);
+        assert_eq!(cache.peek_lru(), Some((&"b", &2)));
+
+        // Putting an existing key replaces its value and promotes it.
+        assert_eq!(cache.put("b", 20), Some(2));
+        assert_eq!(keys(cache.iter()), vec![&"b", &"a", &"c"]);
+        *cache.get_mut("c").unwrap() += 30;
+        assert_eq!(cache.peek("c"), Some(&33));
+        assert_eq!(cache.get("z"), None);
+        assert_eq!(format!("{cache:?}"), r#"{"c": 33, "b": 20, "a": 1}"#);
+    }
+
+    #[test]
+    fn test_put_evicts_least_recently_used() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let mut cache: LruCache<i32, String, 2> = LruCache::new();
+        let log = evicted.clone();
+        cache.set_eviction_callback(move |key, value| log.lock().unwrap().push((key, value)));
+
+        cache.put(1, "one".to_string());
+        cache.put(2, "two".to_string());
+        cache.get(&1);
+        cache.put(3, "three".to_string());
+        assert!(!cache.contains_key(&2));
+        assert_eq!(keys(cache.iter()), vec![&3, &1]);
+        cache.put(4, "four".to_string());
+        assert_eq!(
+            *evicted.lock().unwrap(),
+            vec![(2, "two".to_string()), (1, "one".to_string())]
+        );
+
+        // Explicit removals hand the entry back rather than calling the callback.
+        assert_eq!(cache.pop_lru(), Some((3, "three".to_string())));
+        assert_eq!(cache.remove(&4), Some("four".to_string()));
+        assert_eq!(cache.remove(&4), None);
+        assert_eq!(cache.pop_lru(), None);
+        assert!(cache.is_empty());
+        assert_eq!(evicted.lock().unwrap().len(), 2);
+    }
+
+    // Slots freed by removals are reused by later puts without evicting anything.
+    #[test]
+    fn test_free_slots_are_reused() {
+        let mut cache: LruCache<i32, i32, 3> = LruCache::new();
+        for key in 0..3 {
+            cache.put(key, key);
+        }
+        cache.remove(&1);
+        assert!(!cache.is_full());
+        cache.put(3, 3);
+        assert_eq!(keys(cache.iter()), vec![&3, &2, &0]);
+        cache.clear();
+        assert!(cache.is_empty());
+        for key in 10..13 {
+            cache.put(key, key);
+        }
+        assert_eq!(keys(cache.iter()), vec![&12, &11, &10]);
+    }
+
+    // The callback is Send, so a cache with one set can still be moved to another thread.
+    #[test]
+    fn test_cache_with_callback_is_send() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let mut cache: LruCache<i32, i32, 1> = LruCache::new();
+        let log = evicted.clone();
+        cache.set_eviction_callback(move |key, _| log.lock().unwrap().push(key));
+
+        let cache = std::thread::spawn(move || {
+            cache.put(1, 10);
+            cache.put(2, 20);
+            cache
+        })
+        .join()
+        .unwrap();
+        assert_eq!(keys(cache.iter()), vec![&2]);
+        assert_eq!(*evicted.lock().unwrap(), vec![1]);
+    }
+
+    #[test]
+    fn test_zero_capacity_evicts_immediately() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let mut cache: LruCache<i32, i32, 0> = LruCache::new();
+        let log = evicted.clone();
+        cache.set_eviction_callback(move |key, value| log.lock().unwrap().push((key, value)));
+        assert_eq!(cache.put(1, 10), None);
+        assert!(cache.is_empty());
+        assert_eq!(*evicted.lock().unwrap(), vec![(1, 10)]);
+    }
+
+    #[test]
+    fn test_resize_heap_cache() {
+        let evicted = Arc::new(Mutex::new(Vec::new()));
+        let mut cache = HeapLruCache::with_capacity(4);
+        let log = evicted.clone();
+        cache.set_eviction_callback(move |key, _| log.lock().unwrap().push(key));
+        for key in 0..4 {
+            cache.put(key, key * 10);
+        }
+        cache.get(&0);
+        assert_eq!(keys(cache.iter()), vec![&0, &3, &2, &1]);
+
+        cache.resize(2);
+        assert_eq!(cache.capacity(), 2);
+        assert_eq!(*evicted.lock().unwrap(), vec![1, 2]);
+        assert_eq!(keys(cache.iter()), vec![&0, &3]);
+        assert_eq!(cache.peek(&3), Some(&30));
+
+        cache.resize(5);
+        assert_eq!(cache.capacity(), 5);
+        for key in 4..7 {
+            cache.put(key, key * 10);
+        }
+        assert!(cache.is_full());
+        assert_eq!(keys(cache.iter()), vec![&6, &5, &4, &0, &3]);
+        assert_eq!(evicted.lock().unwrap().len(), 2);
+
+        cache.resize(0);
+        assert!(cache.is_empty());
+        assert_eq!(*evicted.lock().unwrap(), vec![1, 2, 3, 0, 4, 5, 6]);
+    }
+
+    // Random operations against a model kept as a Vec of (key, value) pairs, most recent first.
+    #[test]
+    fn test_matches_model() {
+        const CAPACITY: usize = 8;
+        let mut cache: LruCache<u64, u64, CAPACITY> = LruCache::new();
+        let mut model: Vec<(u64, u64)> = Vec::new();
+        let mut state = 0x2545_F491_4F6C_DD1Du64;
+        for step in 0..10_000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            let key = state % 16;
+            match state >> 32 & 3 {
+                0 => {
+                    let position = model.iter().position(|(k, _)| *k == key);
+                    let expected = position.map(|i| model.remove(i));
+                    assert_eq!(cache.put(key, step), expected.map(|(_, v)| v));
+                    if model.len() == CAPACITY {
+                        model.pop();
+                    }
+                    model.insert(0, (key, step));
+                }
+                1 => {
+                    let position = model.iter().position(|(k, _)| *k == key);
+                    let expected = position.map(|i| model.remove(i));
+                    assert_eq!(cache.get(&key).copied(), expected.map(|(_, v)| v));
+                    if let Some(entry) = expected {
+                        model.insert(0, entry);
+                    }
+                }
+                2 => {
+                    let position = model.iter().position(|(k, _)| *k == key);
+                    let expected = position.map(|i| model.remove(i).1);
+                    assert_eq!(cache.remove(&key), expected);
+                }
+                _ => assert_eq!(cache.pop_lru(), model.pop()),
+            }
+            let actual: Vec<(u64, u64)> = cache.iter().map(|(k, v)| (*k, *v)).collect();
+            assert_eq!(actual, model);
+        }
+    }
+}
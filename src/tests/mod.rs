@@ -1,2 +1,47 @@
+pub mod adapters_test;
+pub mod atomic_pool_test;
+pub mod circular_test;
+pub mod concurrent_test;
+pub mod dot_test;
 pub mod dynamic_test;
+pub mod intrusive_test;
+pub mod invariants_test;
+pub mod lru_test;
+pub mod model_test;
+pub mod persistent_test;
+pub mod repl_test;
+pub mod serde_test;
+pub mod skip_test;
+pub mod snapshot_test;
+pub mod sorted_test;
 pub mod static_test;
+pub mod sync_test;
+pub mod undo_test;
+pub mod unique_test;
+pub mod unrolled_test;
+pub mod xor_test;
+
+// SplitMix64, the seeded random number generator every randomised test module uses, so a given
+// seed does the same operations on every run and platform. Any seed works, including 0.
+#[cfg(test)]
+pub struct Rng(u64);
+
+#[cfg(test)]
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng(seed)
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Returns a number in [0, n).
+    pub fn below(&mut self, n: u64) -> u64 {
+        self.next_u64() % n
+    }
+}
@@ -0,0 +1,409 @@
+// Differential tests: random operation sequences are run against the lists and a Vec model,
+// and the two are compared after every step. A failing sequence is shrunk before it is reported,
+// together with the seed that produced it.
+//
+// Replay a failure with MODEL_SEED=<seed> cargo test model_tests
+// and run more cases with MODEL_CASES=<count>.
+#[cfg(test)]
+mod model_tests {
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::invariants::InvariantViolation;
+    use crate::static_list::StaticLinkedList;
+    use crate::tests::Rng;
+
+    const DEFAULT_SEED: u64 = 0x5EED_CAFE_F00D_0001;
+    const DEFAULT_CASES: u64 = 200;
+    const OPS_PER_CASE: usize = 60;
+
+    #[derive(Debug, Clone, Copy, PartialEq)]
+    enum Op {
+        Insert(i32),
+        InsertAt(usize, i32),
+        DeleteElement(i32),
+        DeleteAt(usize),
+        UpdateElement(i32, i32),
+        UpdateAt(usize, i32),
+        Find(i32),
+        Get(usize),
+    }
+
+    impl Op {
+        // Returns simpler versions of the operation for shrinking: smaller values and indices.
+        fn simplifications(self) -> Vec<Op> {
+            let smaller = |v: i32| if v == 0 { vec![] } else { vec![0] };
+            let lower = |i: usize| if i == 0 { vec![] } else { vec![0, i - 1] };
+            match self {
+                Op::Insert(v) => smaller(v).into_iter().map(Op::Insert).collect(),
+                Op::InsertAt(i, v) => lower(i)
+                    .into_iter()
+                    .map(|i| Op::InsertAt(i, v))
+                    .chain(smaller(v).into_iter().map(|v| Op::InsertAt(i, v)))
+                    .collect(),
+                Op::DeleteElement(v) => smaller(v).into_iter().map(Op::DeleteElement).collect(),
+                Op::DeleteAt(i) => lower(i).into_iter().map(Op::DeleteAt).collect(),
+                Op::UpdateElement(old, new) => smaller(old)
+                    .into_iter()
+                    .map(|old| Op::UpdateElement(old, new))
+                    .chain(
+                        smaller(new)
+                            .into_iter()
+                            .map(|new| Op::UpdateElement(old, new)),
+                    )
+                    .collect(),
+                Op::UpdateAt(i, v) => lower(i)
+                    .into_iter()
+                    .map(|i| Op::UpdateAt(i, v))
+                    .chain(smaller(v).into_iter().map(|v| Op::UpdateAt(i, v)))
+                    .collect(),
+                Op::Find(v) => smaller(v).into_iter().map(Op::Find).collect(),
+                Op::Get(i) => lower(i).into_iter().map(Op::Get).collect(),
+            }
+        }
+    }
+
+    // Generates a sequence of operations. Values come from a small range so duplicates are
+    // common, and indices reach a little past the end so out of bounds cases come up.
+    // insert_weight: u64: How many times more likely an Insert is than each other operation.
+    fn generate(rng: &mut Rng, count: usize, insert_weight: u64) -> Vec<Op> {
+        let mut ops = Vec::with_capacity(count);
+        let mut len_hint = 0usize;
+        for _ in 0..count {
+            let value = rng.below(8) as i32;
+            let index = rng.below(len_hint as u64 + 3) as usize;
+            let op = match rng.below(7 + insert_weight) {
+                0 => Op::InsertAt(index, value),
+                1 => Op::DeleteElement(value),
+                2 => Op::DeleteAt(index),
+                3 => Op::UpdateElement(value, rng.below(8) as i32),
+                4 => Op::UpdateAt(index, value),
+                5 => Op::Find(value),
+                6 => Op::Get(index),
+                _ => Op::Insert(value),
+            };
+            match op {
+                Op::Insert(_) | Op::InsertAt(..) => len_hint += 1,
+                Op::DeleteElement(_) | Op::DeleteAt(_) => len_hint = len_hint.saturating_sub(1),
+                _ => {}
+            }
+            ops.push(op);
+        }
+        ops
+    }
+
+    // What an operation returned, so results can be compared as well as contents.
+    #[derive(Debug, PartialEq)]
+    enum Outcome {
+        Unit,
+        Bool(bool),
+        Value(Option<i32>),
+    }
+
+    // A list under test, or the model it is compared with.
+    trait Subject: Default {
+        fn apply(&mut self, op: Op) -> Outcome;
+        fn contents(&self) -> Vec<i32>;
+        fn validate(&self) -> Result<(), InvariantViolation>;
+    }
+
+    // The reference behaviour: a Vec with an optional capacity.
+    // Inserting into a full model, or at an index past the end, changes nothing.
+    struct Model {
+        items: Vec<i32>,
+        capacity: Option<usize>,
+    }
+
+    impl Model {
+        fn new(capacity: Option<usize>) -> Self {
+            Model {
+                items: Vec::new(),
+                capacity,
+            }
+        }
+
+        fn is_full(&self) -> bool {
+            self.capacity == Some(self.items.len())
+        }
+
+        fn apply(&mut self, op: Op) -> Outcome {
+            match op {
+                Op::Insert(v) => {
+                    if !self.is_full() {
+                        self.items.push(v);
+                    }
+                    Outcome::Unit
+                }
+                Op::InsertAt(i, v) => {
+                    if i <= self.items.len() && !self.is_full() {
+                        self.items.insert(i, v);
+                    }
+                    Outcome::Unit
+                }
+                Op::DeleteElement(v) => {
+                    let position = self.items.iter().position(|item| *item == v);
+                    Outcome::Bool(position.map(|i| self.items.remove(i)).is_some())
+                }
+                Op::DeleteAt(i) => {
+                    let in_bounds = i < self.items.len();
+                    if in_bounds {
+                        self.items.remove(i);
+                    }
+                    Outcome::Bool(in_bounds)
+                }
+                Op::UpdateElement(old, new) => {
+                    let item = self.items.iter_mut().find(|item| **item == old);
+                    Outcome::Bool(item.map(|item| *item = new).is_some())
+                }
+                Op::UpdateAt(i, v) => {
+                    Outcome::Bool(self.items.get_mut(i).map(|item| *item = v).is_some())
+                }
+                Op::Find(v) => Outcome::Bool(self.items.contains(&v)),
+                Op::Get(i) => Outcome::Value(self.items.get(i).copied()),
+            }
+        }
+    }
+
+    impl Subject for DynamicLinkedList<i32> {
+        fn apply(&mut self, op: Op) -> Outcome {
+            match op {
+                Op::Insert(v) => {
+                    self.insert(v);
+                    Outcome::Unit
+                }
+                Op::InsertAt(i, v) => {
+                    self.insert_at_index(i, v);
+                    Outcome::Unit
+                }
+                Op::DeleteElement(v) => Outcome::Bool(self.delete_element(v)),
+                Op::DeleteAt(i) => Outcome::Bool(self.delete_at_index(i)),
+                Op::UpdateElement(old, new) => Outcome::Bool(self.update_element(old, new)),
+                Op::UpdateAt(i, v) => Outcome::Bool(self.update_element_at_index(i, v)),
+                Op::Find(v) => Outcome::Bool(self.find(v)),
+                Op::Get(i) => Outcome::Value(self.get(i)),
+            }
+        }
+
+        fn contents(&self) -> Vec<i32> {
+            self.iter().copied().collect()
+        }
+
+        fn validate(&self) -> Result<(), InvariantViolation> {
+            DynamicLinkedList::validate(self)
+        }
+    }
+
+    impl<const N: usize> Subject for StaticLinkedList<i32, N> {
+        fn apply(&mut self, op: Op) -> Outcome {
+            match op {
+                Op::Insert(v) => {
+                    self.insert(v);
+                    Outcome::Unit
+                }
+                Op::InsertAt(i, v) => {
+                    self.insert_at_index(i, v);
+                    Outcome::Unit
+                }
+                Op::DeleteElement(v) => Outcome::Bool(self.delete_element(v)),
+                Op::DeleteAt(i) => Outcome::Bool(self.delete_at_index(i)),
+                Op::UpdateElement(old, new) => Outcome::Bool(self.update_element(old, new)),
+                Op::UpdateAt(i, v) => Outcome::Bool(self.update_element_at_index(i, v)),
+                Op::Find(v) => Outcome::Bool(self.find(v)),
+                Op::Get(i) => Outcome::Value(self.get(i)),
+            }
+        }
+
+        fn contents(&self) -> Vec<i32> {
+            self.iter().copied().collect()
+        }
+
+        fn validate(&self) -> Result<(), InvariantViolation> {
+            StaticLinkedList::validate(self)
+        }
+    }
+
+    // Runs ops against a fresh subject and a model with the given capacity.
+    // Returns a description of the first step where they disagree.
+    fn check<S: Subject>(ops: &[Op], capacity: Option<usize>) -> Result<(), String> {
+        let mut subject = S::default();
+        let mut model = Model::new(capacity);
+        for (step, op) in ops.iter().enumerate() {
+            let expected = model.apply(*op);
+            let actual = subject.apply(*op);
+            if actual != expected {
+                return Err(format!(
+                    "step {step} {op:?} returned {actual:?}, model returned {expected:?}"
+                ));
+            }
+            if subject.contents() != model.items {
+                return Err(format!(
+                    "after step {step} {op:?} the list holds {:?}, model holds {:?}",
+                    subject.contents(),
+                    model.items
+                ));
+            }
+            if let Err(violation) = subject.validate() {
+                return Err(format!("after step {step} {op:?}: {violation}"));
+            }
+        }
+        Ok(())
+    }
+
+    // Shrinks a failing sequence: first drops chunks of operations, halving the chunk size
+    // down to single operations, then simplifies the values and indices of what is left.
+    // Every candidate is kept only if it still fails.
+    fn shrink(mut ops: Vec<Op>, fails: impl Fn(&[Op]) -> bool) -> Vec<Op> {
+        let mut chunk = ops.len().div_ceil(2).max(1);
+        loop {
+            let mut start = 0;
+            while start < ops.len() {
+                let end = (start + chunk).min(ops.len());
+                let candidate: Vec<Op> = ops[..start].iter().chain(&ops[end..]).copied().collect();
+                if fails(&candidate) {
+                    ops = candidate;
+                } else {
+                    start += chunk;
+                }
+            }
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for i in 0..ops.len() {
+                for simpler in ops[i].simplifications() {
+                    let mut candidate = ops.clone();
+                    candidate[i] = simpler;
+                    if fails(&candidate) {
+                        ops = candidate;
+                        changed = true;
+                        break;
+                    }
+                }
+            }
+        }
+        ops
+    }
+
+    fn env_u64(name: &str) -> Option<u64> {
+        let value = std::env::var(name).ok()?;
+        let value = value.trim();
+        match value.strip_prefix("0x") {
+            Some(hex) => u64::from_str_radix(hex, 16).ok(),
+            None => value.parse().ok(),
+        }
+    }
+
+    // Runs many random cases against S, panicking with the seed and a shrunk
+    // sequence on the first failure. MODEL_SEED replays a single case.
+    // insert_weight: u64: Passed to generate; higher values fill the list faster.
+    fn run<S: Subject>(name: &str, capacity: Option<usize>, insert_weight: u64) {
+        let seeds: Vec<u64> = match env_u64("MODEL_SEED") {
+            Some(seed) => vec![seed],
+            None => {
+                let cases = env_u64("MODEL_CASES").unwrap_or(DEFAULT_CASES);
+                let mut rng = Rng::new(DEFAULT_SEED);
+                (0..cases).map(|_| rng.next_u64()).collect()
+            }
+        };
+
+        for seed in seeds {
+            let ops = generate(&mut Rng::new(seed), OPS_PER_CASE, insert_weight);
+            if let Err(first) = check::<S>(&ops, capacity) {
+                let minimal = shrink(ops, |ops| check::<S>(ops, capacity).is_err());
+                let reason = check::<S>(&minimal, capacity).unwrap_err();
+                panic!(
+                    "{name} diverged from the model (seed {seed:#x}, replay with MODEL_SEED={seed:#x})\n\
+                     first failure: {first}\n\
+                     shrunk to {} op(s): {minimal:?}\n\
+                     {reason}",
+                    minimal.len()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_dynamic_matches_model() {
+        run::<DynamicLinkedList<i32>>("DynamicLinkedList", None, 3);
+    }
+
+    #[test]
+    fn test_static_matches_model() {
+        run::<StaticLinkedList<i32, 16>>("StaticLinkedList<16>", Some(16), 3);
+    }
+
+    #[test]
+    fn test_static_capacity_exhaustion_matches_model() {
+        // Mostly inserts into a small list, so it spends most of the run full.
+        run::<StaticLinkedList<i32, 4>>("StaticLinkedList<4>", Some(4), 12);
+        run::<StaticLinkedList<i32, 1>>("StaticLinkedList<1>", Some(1), 6);
+    }
+
+    #[test]
+    fn test_rng_is_deterministic() {
+        let mut a = Rng::new(42);
+        let mut b = Rng::new(42);
+        for _ in 0..100 {
+            assert_eq!(a.next_u64(), b.next_u64());
+        }
+        assert_eq!(
+            generate(&mut Rng::new(7), 20, 3),
+            generate(&mut Rng::new(7), 20, 3)
+        );
+    }
+
+    // A list that ignores every insert once it holds three elements,
+    // to check the harness catches and shrinks a real divergence.
+    struct ForgetfulList(Model);
+
+    impl Default for ForgetfulList {
+        fn default() -> Self {
+            ForgetfulList(Model::new(None))
+        }
+    }
+
+    impl Subject for ForgetfulList {
+        fn apply(&mut self, op: Op) -> Outcome {
+            if let Op::Insert(_) = op
+                && self.0.items.len() >= 3
+            {
+                return Outcome::Unit;
+            }
+            self.0.apply(op)
+        }
+
+        fn contents(&self) -> Vec<i32> {
+            self.0.items.clone()
+        }
+
+        fn validate(&self) -> Result<(), InvariantViolation> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_shrinking_reduces_to_minimal_case() {
+        let mut rng = Rng::new(DEFAULT_SEED);
+        let ops = (0..50)
+            .map(|_| generate(&mut rng, OPS_PER_CASE, 3))
+            .find(|ops| check::<ForgetfulList>(ops, None).is_err())
+            .expect("some generated sequence reaches four elements");
+
+        let minimal = shrink(ops, |ops| check::<ForgetfulList>(ops, None).is_err());
+        assert_eq!(minimal, vec![Op::Insert(0); 4]);
+    }
+
+    #[test]
+    fn test_failure_report_names_seed() {
+        let result = std::panic::catch_unwind(|| run::<ForgetfulList>("ForgetfulList", None, 3));
+        let payload = result.expect_err("the forgetful list diverges");
+        let message = payload
+            .downcast_ref::<String>()
+            .expect("panic message is a String");
+        assert!(message.contains("replay with MODEL_SEED=0x"));
+        assert!(message.contains("shrunk to 4 op(s)"));
+    }
+}
@@ -0,0 +1,104 @@
+#[cfg(test)]
+mod persistent_tests {
+    use crate::persistent_list::PersistentList;
+
+    fn contents(list: &PersistentList<i32>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_push_front_and_tail_keep_old_versions() {
+        let empty = PersistentList::new();
+        let one = empty.push_front(1);
+        let two = one.push_front(2);
+        let three = two.push_front(3);
+
+        assert_eq!(contents(&three), vec![3, 2, 1]);
+        assert_eq!(contents(&two), vec![2, 1]);
+        assert_eq!(contents(&one), vec![1]);
+        assert!(empty.is_empty());
+
+        let tail = three.tail().unwrap();
+        assert_eq!(tail, two);
+        assert!(tail.ptr_eq(&two)); // the same nodes, not a copy
+        assert_eq!(three.front(), Some(&3));
+        assert!(empty.tail().is_none());
+        assert_eq!(one.tail().unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_insert_at_index_shares_suffix() {
+        let list: PersistentList<i32> = (0..5).collect();
+        let inserted = list.insert_at_index(2, 9).unwrap();
+
+        assert_eq!(contents(&inserted), vec![0, 1, 9, 2, 3, 4]);
+        assert_eq!(contents(&list), vec![0, 1, 2, 3, 4]);
+        assert_eq!(inserted.len(), 6);
+
+        // Everything from the insertion point onwards is the original list's nodes.
+        let shared = inserted.tail().unwrap().tail().unwrap().tail().unwrap();
+        let original = list.tail().unwrap().tail().unwrap();
+        assert!(shared.ptr_eq(&original));
+
+        assert_eq!(
+            contents(&list.insert_at_index(5, 5).unwrap()),
+            vec![0, 1, 2, 3, 4, 5]
+        );
+        assert_eq!(contents(&list.insert_at_index(0, -1).unwrap())[0], -1);
+        assert!(list.insert_at_index(6, 0).is_none());
+    }
+
+    #[test]
+    fn test_update_element_at_index_shares_suffix() {
+        let list: PersistentList<i32> = (0..4).collect();
+        let updated = list.update_element_at_index(1, 7).unwrap();
+
+        assert_eq!(contents(&updated), vec![0, 7, 2, 3]);
+        assert_eq!(contents(&list), vec![0, 1, 2, 3]);
+        assert_eq!(updated.get(1), Some(&7));
+
+        let after = updated.tail().unwrap().tail().unwrap();
+        assert!(after.ptr_eq(&list.tail().unwrap().tail().unwrap()));
+        assert!(list.update_element_at_index(4, 0).is_none());
+    }
+
+    #[test]
+    fn test_clone_is_shallow() {
+        let list: PersistentList<String> = ["a", "b"].into_iter().map(String::from).collect();
+        let copy = list.clone();
+        assert!(copy.ptr_eq(&list));
+
+        let separate: PersistentList<String> = ["a", "b"].into_iter().map(String::from).collect();
+        assert_eq!(separate, list);
+        assert!(!separate.ptr_eq(&list));
+        assert_eq!(format!("{list:?}"), "[\"a\", \"b\"]");
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let mut list = PersistentList::new();
+        for i in 0..200_000 {
+            list = list.push_front(i);
+        }
+        let version = list.tail().unwrap();
+        drop(list); // must not overflow the stack
+        assert_eq!(version.len(), 199_999);
+        assert_eq!(version.front(), Some(&199_998));
+        drop(version);
+    }
+
+    #[test]
+    fn test_versions_can_move_between_threads() {
+        let base: PersistentList<i32> = (0..3).collect();
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                let base = base.clone();
+                std::thread::spawn(move || base.push_front(i).len())
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap(), 4);
+        }
+        assert_eq!(contents(&base), vec![0, 1, 2]);
+    }
+}
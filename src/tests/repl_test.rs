@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod repl_tests {
+    use crate::repl::{
+        Command, Expectation, ParseError, ScriptError, Storage, new_session, run_script,
+    };
+
+    fn run(storage: Storage, lines: &[&str]) -> Vec<Result<String, String>> {
+        let mut session = new_session(storage);
+        lines
+            .iter()
+            .map(|line| session.execute(Command::parse(line).unwrap().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_commands() {
+        assert_eq!(Command::parse("insert 5"), Ok(Some(Command::Insert(5))));
+        assert_eq!(
+            Command::parse("  insert_at 2 -7 "),
+            Ok(Some(Command::InsertAt(2, -7)))
+        );
+        assert_eq!(
+            Command::parse("update 3 9"),
+            Ok(Some(Command::Update(3, 9)))
+        );
+        assert_eq!(Command::parse("exit"), Ok(Some(Command::Quit)));
+        assert_eq!(Command::parse(""), Ok(None));
+        assert_eq!(Command::parse("# a comment"), Ok(None));
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(
+            Command::parse("push 1"),
+            Err(ParseError::UnknownCommand("push".to_string()))
+        );
+        assert_eq!(
+            Command::parse("delete_at"),
+            Err(ParseError::MissingArgument {
+                command: "delete_at",
+                argument: "index"
+            })
+        );
+        assert_eq!(
+            Command::parse("get -1"),
+            Err(ParseError::InvalidNumber {
+                argument: "index",
+                found: "-1".to_string()
+            })
+        );
+        assert_eq!(
+            Command::parse("print now"),
+            Err(ParseError::TooManyArguments("print now".to_string()))
+        );
+        assert_eq!(
+            Command::parse("delete_at").unwrap_err().to_string(),
+            "`delete_at` is missing its index argument"
+        );
+    }
+
+    #[test]
+    fn test_parse_storage() {
+        assert_eq!(Storage::parse("dynamic"), Ok(Storage::Dynamic));
+        assert_eq!(Storage::parse("static 8"), Ok(Storage::Static(8)));
+        assert_eq!(
+            Storage::parse("static 0"),
+            Err(ParseError::CapacityOutOfRange(0))
+        );
+        assert_eq!(
+            Storage::parse("static 65"),
+            Err(ParseError::CapacityOutOfRange(65))
+        );
+        assert!(Storage::parse("static").is_err());
+        assert!(Storage::parse("heap").is_err());
+    }
+
+    #[test]
+    fn test_dynamic_session() {
+        let results = run(
+            Storage::Dynamic,
+            &[
+                "insert 5",
+                "insert_at 0 4",
+                "insert_at 5 1",
+                "get 1",
+                "delete 9",
+                "update 4 6",
+                "find 6",
+                "delete_at 0",
+                "print",
+                "validate",
+            ],
+        );
+        assert_eq!(
+            results,
+            vec![
+                Ok("inserted 5".to_string()),
+                Ok("inserted 4 at index 0".to_string()),
+                Err("index 5 is out of bounds for a list of length 2".to_string()),
+                Ok("5".to_string()),
+                Err("9 is not in the list".to_string()),
+                Ok("updated 4 to 6".to_string()),
+                Ok("6 is in the list".to_string()),
+                Ok("deleted 6 at index 0".to_string()),
+                Ok("[5]".to_string()),
+                Ok("ok".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_static_session_reports_full() {
+        let results = run(
+            Storage::Static(2),
+            &["insert 1", "insert 2", "insert 3", "insert_at 0 3", "print"],
+        );
+        assert_eq!(
+            results,
+            vec![
+                Ok("inserted 1".to_string()),
+                Ok("inserted 2".to_string()),
+                Err("the list is full, 3 was not inserted".to_string()),
+                Err("the list is full, 3 was not inserted".to_string()),
+                Ok("[1, 2] (2 of 2 slots used)".to_string()),
+            ]
+        );
+
+        let dot = run(Storage::Static(2), &["dot"]).remove(0).unwrap();
+        assert!(dot.starts_with("digraph StaticLinkedList {"));
+    }
+
+    #[test]
+    fn test_parse_expectations() {
+        assert_eq!(
+            Command::parse("expect get 1 == 7"),
+            Ok(Some(Command::Expect(Expectation::Get(1, Some(7)))))
+        );
+        assert_eq!(
+            Command::parse("expect get 4 == none"),
+            Ok(Some(Command::Expect(Expectation::Get(4, None))))
+        );
+        assert_eq!(
+            Command::parse("expect find -3 == false"),
+            Ok(Some(Command::Expect(Expectation::Find(-3, false))))
+        );
+        assert_eq!(
+            Command::parse("expect len==0"),
+            Ok(Some(Command::Expect(Expectation::Len(0))))
+        );
+        assert_eq!(
+            Command::parse("expect list == [1, -2,3]"),
+            Ok(Some(Command::Expect(Expectation::List(vec![1, -2, 3]))))
+        );
+        assert_eq!(
+            Command::parse("expect list == []"),
+            Ok(Some(Command::Expect(Expectation::List(vec![]))))
+        );
+        assert_eq!(
+            Command::parse("expect get 1 = 7"),
+            Err(ParseError::BadExpectation("get 1 = 7".to_string()))
+        );
+        assert!(Command::parse("expect list == [1, x]").is_err());
+    }
+
+    #[test]
+    fn test_run_script_counts_failures() {
+        let script = "\
+            # starts on the static list chosen here
+            storage static 2
+            insert 1
+            insert 2
+            insert 3
+            expect list == [1, 2]
+            expect get 0 == 2
+            validate
+        ";
+        let mut out = Vec::new();
+        let summary = run_script(script, Storage::Dynamic, false, &mut out).unwrap();
+        assert_eq!(summary.commands, 6);
+        assert_eq!(summary.failures, 1);
+
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("error: the list is full, 3 was not inserted"));
+        assert!(out.contains("FAILED at line 7: expected get 0 == 2, found 1"));
+    }
+
+    #[test]
+    fn test_run_script_trace_shows_layout() {
+        let mut out = Vec::new();
+        run_script("insert 4\ndelete_at 0", Storage::Static(2), true, &mut out).unwrap();
+        let out = String::from_utf8(out).unwrap();
+        assert!(out.contains("head: -  free: 0"));
+        assert!(out.contains("slot 0: free"));
+    }
+
+    #[test]
+    fn test_run_script_errors() {
+        let mut out = Vec::new();
+        let result = run_script(
+            "insert 1\nstorage static 2",
+            Storage::Dynamic,
+            false,
+            &mut out,
+        );
+        assert!(matches!(result, Err(ScriptError::LateStorage { line: 2 })));
+
+        let result = run_script("\ninsert x", Storage::Dynamic, false, &mut out);
+        assert!(matches!(result, Err(ScriptError::Parse { line: 2, .. })));
+    }
+}
@@ -0,0 +1,99 @@
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::serde_impls::raw;
+    use crate::static_list::StaticLinkedList;
+
+    fn to_raw_json<const N: usize>(list: &StaticLinkedList<i32, N>) -> String {
+        let mut out = Vec::new();
+        raw::serialize(list, &mut serde_json::Serializer::new(&mut out)).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    fn from_raw_json<const N: usize>(
+        json: &str,
+    ) -> Result<StaticLinkedList<i32, N>, serde_json::Error> {
+        raw::deserialize(&mut serde_json::Deserializer::from_str(json))
+    }
+
+    #[test]
+    fn test_dynamic_round_trip() {
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        list.insert(2);
+        list.insert(3);
+
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2,3]");
+
+        let back: DynamicLinkedList<i32> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_static_round_trip() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(2);
+
+        let json = serde_json::to_string(&list).unwrap();
+        assert_eq!(json, "[1,2]");
+
+        let back: StaticLinkedList<i32, 4> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_static_rejects_too_many_items() {
+        let result = serde_json::from_str::<StaticLinkedList<i32, 2>>("[1,2,3]");
+        let message = result.err().unwrap().to_string();
+        assert!(message.contains("at most 2 elements"), "{message}");
+    }
+
+    #[test]
+    fn test_raw_round_trip_keeps_layout() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(2);
+        list.insert(3);
+        list.delete_at_index(0); // slot 0 goes back to the free list
+
+        let json = to_raw_json(&list);
+        let back = from_raw_json::<4>(&json).unwrap();
+
+        assert_eq!(back.head_slot(), list.head_slot());
+        assert_eq!(back.free_slot(), list.free_slot());
+        assert!(back.slots().eq(list.slots()));
+        assert_eq!(back.len(), 2);
+
+        let mut back = back;
+        back.insert(4);
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_raw_rejects_bad_layouts() {
+        // Wrong capacity
+        let empty = to_raw_json(&StaticLinkedList::<i32, 2>::new());
+        assert!(from_raw_json::<3>(&empty).is_err());
+
+        // A live chain that loops back on itself
+        let cycle = r#"{"capacity":2,"head":0,"free":null,"slots":[{"data":1,"next":1},{"data":2,"next":0}]}"#;
+        assert!(from_raw_json::<2>(cycle).is_err());
+
+        // An index past the end of the array
+        let out_of_range = r#"{"capacity":2,"head":0,"free":1,"slots":[{"data":1,"next":5},{"data":null,"next":null}]}"#;
+        assert!(from_raw_json::<2>(out_of_range).is_err());
+
+        // A free slot that still holds data
+        let occupied_free = r#"{"capacity":2,"head":0,"free":1,"slots":[{"data":1,"next":null},{"data":2,"next":null}]}"#;
+        assert!(from_raw_json::<2>(occupied_free).is_err());
+
+        // A slot on neither chain
+        let orphan = r#"{"capacity":2,"head":0,"free":null,"slots":[{"data":1,"next":null},{"data":null,"next":null}]}"#;
+        assert!(from_raw_json::<2>(orphan).is_err());
+
+        let valid = r#"{"capacity":2,"head":1,"free":0,"slots":[{"data":null,"next":null},{"data":7,"next":null}]}"#;
+        assert_eq!(from_raw_json::<2>(valid).unwrap().get(0), Some(7));
+    }
+}
@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod skip_tests {
+    use std::collections::BTreeSet;
+    use std::ops::Bound;
+
+    use crate::skip_list::{SkipList, SkipSet};
+    use crate::tests::Rng;
+
+    #[test]
+    fn test_indexed_operations() {
+        let mut list = SkipList::new();
+        assert!(list.is_empty());
+        list.insert_at_index(0, 1);
+        list.insert_at_index(1, 3);
+        list.insert_at_index(1, 2);
+        list.insert_at_index(9, 9); // Out of bounds
+        list.push_back(4);
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(3), Some(&4));
+        assert_eq!(list.get(4), None);
+        assert!(list.find(3));
+        assert!(!list.find(7));
+
+        assert!(list.update_element_at_index(1, 20));
+        assert!(!list.update_element_at_index(4, 0));
+        assert!(list.delete_at_index(0));
+        assert!(!list.delete_at_index(3));
+        assert_eq!(list.remove_at_index(2), Some(4));
+        assert_eq!(format!("{list:?}"), "[20, 3]");
+
+        list.clear();
+        assert!(list.is_empty());
+        list.push_back(5);
+        assert_eq!(list.get(0), Some(&5));
+    }
+
+    // Random inserts, deletes and updates at random indices, checked against a Vec after each step.
+    #[test]
+    fn test_matches_vec_model() {
+        for seed in 0..4 {
+            let mut list = SkipList::with_seed(seed);
+            let mut model = Vec::new();
+            let mut rng = Rng::new(0x9E37_79B9 + seed);
+            for step in 0..3_000 {
+                let index = rng.below(model.len() as u64 + 1) as usize;
+                match rng.below(4) {
+                    0 | 1 => {
+                        list.insert_at_index(index, step);
+                        model.insert(index, step);
+                    }
+                    2 => {
+                        let expected = (index < model.len()).then(|| model.remove(index));
+                        assert_eq!(list.remove_at_index(index), expected);
+                    }
+                    _ => {
+                        let updated = index < model.len();
+                        if updated {
+                            model[index] = step;
+                        }
+                        assert_eq!(list.update_element_at_index(index, step), updated);
+                    }
+                }
+                assert_eq!(list.len(), model.len());
+                let probe = rng.below(model.len() as u64 + 1) as usize;
+                assert_eq!(list.get(probe), model.get(probe), "step {step}");
+            }
+            assert!(list.iter().eq(model.iter()));
+            for (index, value) in model.iter().enumerate() {
+                assert_eq!(list.get(index), Some(value));
+            }
+        }
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let mut set: SkipSet<i32> = [5, 1, 9, 3, 7].into_iter().collect();
+        assert!(!set.insert(3));
+        assert!(set.insert(4));
+        assert_eq!(
+            set.iter().copied().collect::<Vec<_>>(),
+            vec![1, 3, 4, 5, 7, 9]
+        );
+        assert!(set.contains(&7));
+        assert!(!set.contains(&8));
+        assert_eq!(set.rank(&5), Some(3));
+        assert_eq!(set.rank(&6), None);
+        assert_eq!(set.get(2), Some(&4));
+        assert_eq!((set.first(), set.last()), (Some(&1), Some(&9)));
+
+        assert_eq!(set.remove(&4), Some(4));
+        assert_eq!(set.remove(&4), None);
+        assert!(set.delete_at_index(0));
+        assert_eq!(format!("{set:?}"), "{3, 5, 7, 9}");
+    }
+
+    #[test]
+    fn test_set_range() {
+        let set: SkipSet<i32> = (0..20).map(|value| value * 5).collect();
+        assert!(set.range(10..25).eq(&[10, 15, 20]));
+        assert!(set.range(11..=25).eq(&[15, 20, 25]));
+        assert!(set.range(..10).eq(&[0, 5]));
+        assert!(set.range(90..).eq(&[90, 95]));
+        assert!(set.range((Bound::Excluded(90), Bound::Unbounded)).eq(&[95]));
+        assert_eq!(set.range(200..).count(), 0);
+        assert_eq!(set.range(..).count(), 20);
+    }
+
+    #[test]
+    fn test_set_matches_btree_set() {
+        let mut set = SkipSet::with_seed(42);
+        let mut model = BTreeSet::new();
+        let mut rng = Rng::new(0xDEAD_BEEFu64);
+        for _ in 0..5_000 {
+            let value = rng.below(256);
+            match rng.below(3) {
+                0 | 1 => assert_eq!(set.insert(value), model.insert(value)),
+                _ => assert_eq!(set.remove(&value), model.take(&value)),
+            }
+        }
+        assert!(set.iter().eq(model.iter()));
+        for (rank, value) in model.iter().enumerate() {
+            assert_eq!(set.rank(value), Some(rank));
+            assert_eq!(set.get(rank), Some(value));
+        }
+        assert!(set.range(50..150).eq(model.range(50..150)));
+    }
+
+    #[test]
+    fn test_large_list_indexing() {
+        let mut list = SkipList::new();
+        for value in 0..100_000 {
+            list.push_back(value);
+        }
+        for index in (0..100_000).step_by(997) {
+            assert_eq!(list.get(index), Some(&index));
+        }
+        for _ in 0..50_000 {
+            list.delete_at_index(0);
+        }
+        assert_eq!(list.get(0), Some(&50_000));
+        assert_eq!(list.len(), 50_000);
+    }
+}
@@ -0,0 +1,210 @@
+#[cfg(test)]
+mod snapshot_tests {
+    use crate::snapshot::{Crc32, FixedCodec, HEADER_SIZE, SnapshotError};
+    use crate::static_list::StaticLinkedList;
+
+    fn sample() -> StaticLinkedList<u32, 4> {
+        let mut list = StaticLinkedList::new();
+        list.insert(10);
+        list.insert(20);
+        list.insert(30);
+        list.delete_at_index(0); // leave a freed slot in the middle of the layout
+        list
+    }
+
+    fn snapshot<const N: usize>(list: &StaticLinkedList<u32, N>) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        list.write_to(&mut bytes).unwrap();
+        bytes
+    }
+
+    // Recomputes the header checksum after a test edits the bytes on purpose.
+    fn reseal(bytes: &mut [u8]) {
+        let mut crc = Crc32::new();
+        crc.update(&bytes[..28]);
+        crc.update(&bytes[HEADER_SIZE..]);
+        let checksum = crc.finish();
+        bytes[28..32].copy_from_slice(&checksum.to_le_bytes());
+    }
+
+    #[test]
+    fn test_round_trip_keeps_layout() {
+        let list = sample();
+        let bytes = snapshot(&list);
+        assert_eq!(bytes.len(), HEADER_SIZE + 4 * (5 + 4));
+
+        let back = StaticLinkedList::<u32, 4>::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(back.iter().copied().collect::<Vec<_>>(), vec![20, 30]);
+        assert_eq!(back.head_slot(), list.head_slot());
+        assert_eq!(back.free_slot(), list.free_slot());
+        assert!(back.slots().eq(list.slots()));
+    }
+
+    #[test]
+    fn test_round_trip_other_types() {
+        let mut list = StaticLinkedList::<char, 3>::new();
+        list.insert('a');
+        list.insert('é');
+        let mut bytes = Vec::new();
+        list.write_to(&mut bytes).unwrap();
+
+        let back = StaticLinkedList::<char, 3>::read_from(bytes.as_slice()).unwrap();
+        assert_eq!(back.iter().copied().collect::<String>(), "aé");
+    }
+
+    #[test]
+    fn test_rejects_header_problems() {
+        let bytes = snapshot(&sample());
+
+        let mut bad_magic = bytes.clone();
+        bad_magic[0] = b'X';
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(bad_magic.as_slice()),
+            Err(SnapshotError::BadMagic)
+        ));
+
+        let mut bad_version = bytes.clone();
+        bad_version[4] = 9;
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(bad_version.as_slice()),
+            Err(SnapshotError::UnsupportedVersion(9))
+        ));
+
+        assert!(matches!(
+            StaticLinkedList::<u32, 5>::read_from(bytes.as_slice()),
+            Err(SnapshotError::CapacityMismatch {
+                expected: 5,
+                found: 4
+            })
+        ));
+        assert!(matches!(
+            StaticLinkedList::<u64, 4>::read_from(bytes.as_slice()),
+            Err(SnapshotError::ElementSizeMismatch {
+                expected: 8,
+                found: 4
+            })
+        ));
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(&bytes[..bytes.len() - 1]),
+            Err(SnapshotError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_flipped_bit() {
+        let mut bytes = snapshot(&sample());
+        let last = bytes.len() - 1;
+        bytes[last] ^= 0x01;
+
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(bytes.as_slice()),
+            Err(SnapshotError::ChecksumMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_rejects_cyclic_and_out_of_range_chains() {
+        let list = sample();
+        let tail = list
+            .slots()
+            .position(|(data, _)| data == Some(&30))
+            .unwrap();
+        let head = list.head_slot().unwrap();
+        let next_offset = |slot: usize| HEADER_SIZE + slot * 9;
+
+        // Point the tail back at the head, with a valid checksum
+        let mut cycle = snapshot(&list);
+        cycle[next_offset(tail)..][..4].copy_from_slice(&(head as u32).to_le_bytes());
+        reseal(&mut cycle);
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(cycle.as_slice()),
+            Err(SnapshotError::Corrupt(_))
+        ));
+
+        // Point the tail past the end of the array
+        let mut out_of_range = snapshot(&list);
+        out_of_range[next_offset(tail)..][..4].copy_from_slice(&77u32.to_le_bytes());
+        reseal(&mut out_of_range);
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(out_of_range.as_slice()),
+            Err(SnapshotError::Corrupt(_))
+        ));
+
+        // Claim more elements than the live chain holds
+        let mut wrong_count = snapshot(&list);
+        wrong_count[24..28].copy_from_slice(&3u32.to_le_bytes());
+        reseal(&mut wrong_count);
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(wrong_count.as_slice()),
+            Err(SnapshotError::Corrupt(_))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_invalid_payload() {
+        let mut list = StaticLinkedList::<bool, 1>::new();
+        list.insert(true);
+        let mut bytes = Vec::new();
+        list.write_to(&mut bytes).unwrap();
+        bytes[HEADER_SIZE + 5] = 2; // not a bool
+        reseal(&mut bytes);
+
+        assert!(matches!(
+            StaticLinkedList::<bool, 1>::read_from(bytes.as_slice()),
+            Err(SnapshotError::InvalidPayload { slot: 0 })
+        ));
+    }
+
+    // Bytes the format says are zero must be zero, even under a valid checksum.
+    #[test]
+    fn test_rejects_non_zero_reserved_and_free_payload() {
+        let list = sample();
+
+        let mut reserved = snapshot(&list);
+        reserved[6] = 1;
+        reseal(&mut reserved);
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(reserved.as_slice()),
+            Err(SnapshotError::ReservedNotZero(1))
+        ));
+
+        let free = list.free_slot().unwrap();
+        let mut payload = snapshot(&list);
+        payload[HEADER_SIZE + free * 9 + 5] = 0xAB;
+        reseal(&mut payload);
+        assert!(matches!(
+            StaticLinkedList::<u32, 4>::read_from(payload.as_slice()),
+            Err(SnapshotError::InvalidPayload { slot }) if slot == free
+        ));
+    }
+
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_write_rejects_element_size_too_large() {
+        // An element type whose size does not fit the header's 32 bit field.
+        struct Huge;
+
+        impl FixedCodec for Huge {
+            const SIZE: usize = u32::MAX as usize + 1;
+
+            fn encode(&self, _: &mut [u8]) {}
+
+            fn decode(_: &[u8]) -> Option<Self> {
+                Some(Huge)
+            }
+        }
+
+        let list = StaticLinkedList::<Huge, 0>::new();
+        assert!(matches!(
+            list.write_to(Vec::new()),
+            Err(SnapshotError::ElementSizeTooLarge(size)) if size == Huge::SIZE
+        ));
+    }
+
+    #[test]
+    fn test_crc32_check_value() {
+        let mut crc = Crc32::new();
+        crc.update(b"123456789");
+        assert_eq!(crc.finish(), 0xCBF4_3926);
+    }
+}
@@ -0,0 +1,212 @@
+#[cfg(test)]
+mod sorted_tests {
+    use crate::sorted_list::{Duplicates, InsertError, SortedList, StaticSortedList};
+
+    #[test]
+    fn test_insert_keeps_order() {
+        let mut list: SortedList<i32> = SortedList::new();
+        for value in [5, 1, 4, 2, 3] {
+            assert_eq!(list.insert(value), Ok(None));
+        }
+
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 3, 4, 5]
+        );
+        assert_eq!(list.first(), Some(&1));
+    }
+
+    // Ordered by priority only, so tasks with the same priority compare equal
+    #[derive(Debug)]
+    struct Task {
+        priority: u8,
+        name: &'static str,
+    }
+
+    impl PartialEq for Task {
+        fn eq(&self, other: &Self) -> bool {
+            self.priority == other.priority
+        }
+    }
+
+    impl Eq for Task {}
+
+    impl PartialOrd for Task {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Task {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            self.priority.cmp(&other.priority)
+        }
+    }
+
+    #[test]
+    fn test_duplicates_allow_keeps_insertion_order() {
+        let mut list: SortedList<Task> = SortedList::new();
+        list.insert(Task {
+            priority: 1,
+            name: "d",
+        })
+        .unwrap();
+        list.insert(Task {
+            priority: 1,
+            name: "b",
+        })
+        .unwrap();
+        list.insert(Task {
+            priority: 0,
+            name: "c",
+        })
+        .unwrap();
+        list.insert(Task {
+            priority: 1,
+            name: "a",
+        })
+        .unwrap();
+
+        let names: Vec<_> = std::iter::from_fn(|| list.pop_first())
+            .map(|task| task.name)
+            .collect();
+        assert_eq!(names, vec!["c", "d", "b", "a"]);
+    }
+
+    #[test]
+    fn test_duplicates_reject_and_replace() {
+        let mut reject: SortedList<i32> = SortedList::with_duplicates(Duplicates::Reject);
+        reject.insert(1).unwrap();
+        assert_eq!(reject.insert(1), Err(InsertError::Duplicate(1)));
+        assert_eq!(reject.len(), 1);
+
+        let mut replace: SortedList<i32> = SortedList::with_duplicates(Duplicates::Replace);
+        replace.insert(1).unwrap();
+        replace.insert(2).unwrap();
+        assert_eq!(replace.insert(2), Ok(Some(2)));
+        assert_eq!(replace.len(), 2);
+    }
+
+    #[test]
+    fn test_contains_and_range() {
+        let mut list: SortedList<i32> = SortedList::new();
+        for value in [10, 30, 20, 50, 40] {
+            list.insert(value).unwrap();
+        }
+
+        assert!(list.contains(&30));
+        assert!(!list.contains(&35));
+        assert!(!list.contains(&99));
+
+        assert_eq!(
+            list.range(20..40).copied().collect::<Vec<_>>(),
+            vec![20, 30]
+        );
+        assert_eq!(
+            list.range(20..=40).copied().collect::<Vec<_>>(),
+            vec![20, 30, 40]
+        );
+        assert_eq!(list.range(..25).copied().collect::<Vec<_>>(), vec![10, 20]);
+        assert_eq!(list.range(45..).copied().collect::<Vec<_>>(), vec![50]);
+        assert_eq!(list.range(31..39).count(), 0);
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut a: SortedList<i32> = SortedList::new();
+        let mut b: SortedList<i32> = SortedList::new();
+        for value in [1, 4, 6] {
+            a.insert(value).unwrap();
+        }
+        for value in [2, 4, 7] {
+            b.insert(value).unwrap();
+        }
+
+        assert_eq!(a.merge(&mut b), Ok(()));
+        assert_eq!(
+            a.iter().copied().collect::<Vec<_>>(),
+            vec![1, 2, 4, 4, 6, 7]
+        );
+        assert!(b.is_empty());
+
+        let mut unique: SortedList<i32> = SortedList::with_duplicates(Duplicates::Reject);
+        let mut other: SortedList<i32> = SortedList::new();
+        unique.insert(1).unwrap();
+        unique.insert(3).unwrap();
+        other.insert(3).unwrap();
+        other.insert(5).unwrap();
+        unique.merge(&mut other).unwrap();
+        assert_eq!(unique.iter().copied().collect::<Vec<_>>(), vec![1, 3, 5]);
+    }
+
+    fn task(priority: u8, name: &'static str) -> Task {
+        Task { priority, name }
+    }
+
+    fn names<S: crate::storage::ListStorage<Task>>(list: &SortedList<Task, S>) -> Vec<&'static str> {
+        list.iter().map(|task| task.name).collect()
+    }
+
+    // An other that allows duplicates can hold equal elements of its own, and a list that
+    // rejects or replaces them must not let those through either.
+    #[test]
+    fn test_merge_duplicates_within_other() {
+        let mut reject: SortedList<Task> = SortedList::with_duplicates(Duplicates::Reject);
+        let mut replace: SortedList<Task> = SortedList::with_duplicates(Duplicates::Replace);
+        for list in [&mut reject, &mut replace] {
+            list.insert(task(2, "kept")).unwrap();
+            let mut other: SortedList<Task> = SortedList::new();
+            for (priority, name) in [(1, "a"), (1, "b"), (2, "c"), (2, "d"), (3, "e"), (3, "f")] {
+                other.insert(task(priority, name)).unwrap();
+            }
+            list.merge(&mut other).unwrap();
+            assert!(other.is_empty());
+        }
+        assert_eq!(names(&reject), vec!["a", "kept", "e"]);
+        assert_eq!(names(&replace), vec!["b", "d", "f"]);
+    }
+
+    #[test]
+    fn test_merge_into_empty_list() {
+        for duplicates in [Duplicates::Allow, Duplicates::Reject, Duplicates::Replace] {
+            let mut list: SortedList<i32> = SortedList::with_duplicates(duplicates);
+            let mut other: SortedList<i32> = SortedList::new();
+            for value in [3, 1, 3, 2, 1] {
+                other.insert(value).unwrap();
+            }
+            list.merge(&mut other).unwrap();
+            let expected = match duplicates {
+                Duplicates::Allow => vec![1, 1, 2, 3, 3],
+                _ => vec![1, 2, 3],
+            };
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        }
+
+        let mut list: StaticSortedList<i32, 2> = SortedList::with_duplicates(Duplicates::Reject);
+        let mut other: StaticSortedList<i32, 2> = SortedList::new();
+        other.insert(5).unwrap();
+        other.insert(5).unwrap();
+        list.merge(&mut other).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![5]);
+    }
+
+    #[test]
+    fn test_static_sorted_list() {
+        let mut list: StaticSortedList<i32, 3> = SortedList::new();
+        list.insert(3).unwrap();
+        list.insert(1).unwrap();
+        list.insert(2).unwrap();
+        assert_eq!(list.insert(0), Err(InsertError::Full(0)));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+
+        let mut other: StaticSortedList<i32, 3> = SortedList::new();
+        other.insert(4).unwrap();
+        list.pop_first();
+        let err = list.merge(&mut other);
+        assert_eq!(err, Ok(()));
+        other.insert(0).unwrap();
+        let err = list.merge(&mut other).unwrap_err();
+        assert_eq!(err.leftover, vec![4]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 2, 3]);
+    }
+}
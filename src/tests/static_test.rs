@@ -119,8 +119,273 @@ mod static_tests {
         assert!(!list.find(999));
     }
 
+    #[test]
+    fn test_insert_at_index_out_of_bounds_keeps_capacity_static() {
+        const N: usize = 2;
+        let mut list = StaticLinkedList::<i32, N>::new();
+
+        list.insert_at_index(5, 1); // invalid index, must not use up a slot
+        list.insert(1);
+        list.insert(2);
+
+        assert_eq!(list.len(), 2);
+        assert!(list.is_full());
+    }
+
+    #[test]
+    fn test_append_static() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        list.insert(1);
+        list.insert(2);
+        let mut other = StaticLinkedList::<i32, 3>::new();
+        other.insert(3);
+
+        assert_eq!(list.append(&mut other), Ok(()));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert!(other.is_empty());
+
+        other.insert(4);
+        other.insert(5);
+        other.insert(6);
+        let err = list.append(&mut other).unwrap_err();
+        assert_eq!(err.leftover, vec![5, 6]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert!(other.is_empty());
+    }
+
+    #[test]
+    fn test_split_off_static() {
+        let mut list = StaticLinkedList::<i32, 5>::new();
+        for i in 0..5 {
+            list.insert(i);
+        }
+
+        let rest = list.split_off(3);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2]);
+        assert_eq!(rest.iter().copied().collect::<Vec<_>>(), vec![3, 4]);
+
+        list.insert(7);
+        list.insert(8); // freed slots are reusable
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 7, 8]);
+
+        assert!(list.split_off(5).is_empty());
+    }
+
+    #[test]
+    fn test_splice_at_static() {
+        let mut list = StaticLinkedList::<i32, 5>::new();
+        list.insert(1);
+        list.insert(4);
+        let mut other = StaticLinkedList::<i32, 5>::new();
+        other.insert(2);
+        other.insert(3);
+
+        assert_eq!(list.splice_at(1, &mut other), Ok(true));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+
+        other.insert(99);
+        assert_eq!(list.splice_at(9, &mut other), Ok(false)); // out of bounds
+        assert_eq!(other.len(), 1);
+
+        other.insert(100);
+        let err = list.splice_at(0, &mut other).unwrap_err();
+        assert_eq!(err.leftover, vec![100]);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![99, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_truncate_and_clear_static() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        for i in 0..4 {
+            list.insert(i);
+        }
+
+        list.truncate(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert!(!list.is_full());
+
+        list.clear();
+        assert!(list.is_empty());
+        for i in 0..4 {
+            list.insert(i);
+        }
+        assert_eq!(list.len(), 4); // every slot came back to the free list
+    }
+
+    #[test]
+    fn test_sort_static() {
+        let mut list = StaticLinkedList::<i32, 10>::new();
+        for value in [5, 3, 9, 1, 4, 8, 2, 7, 6] {
+            list.insert(value);
+        }
+        assert!(!list.is_sorted());
+
+        list.sort();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), (1..=9).collect::<Vec<_>>());
+        assert!(list.is_sorted());
+
+        list.insert(10); // tail must point at the new last slot
+        assert_eq!(list.get(9), Some(10));
+
+        list.sort_by(|a, b| b.cmp(a));
+        assert_eq!(list.get(0), Some(10));
+        assert_eq!(list.get(9), Some(1));
+    }
+
+    #[test]
+    fn test_sort_does_not_move_data_static() {
+        let mut list = StaticLinkedList::<i32, 8>::new();
+        for value in [4, 2, 7, 1, 3] {
+            list.insert(value);
+        }
+        let address_of = |list: &StaticLinkedList<i32, 8>, value: i32| {
+            list.iter().find(|item| **item == value).unwrap() as *const i32
+        };
+        let before: Vec<_> = (1..=7).filter(|v| list.find(*v)).map(|v| address_of(&list, v)).collect();
+
+        list.sort();
+        let after: Vec<_> = (1..=7).filter(|v| list.find(*v)).map(|v| address_of(&list, v)).collect();
+        assert_eq!(before, after);
+    }
+
+    #[test]
+    fn test_sort_by_key_is_stable_static() {
+        let mut list = StaticLinkedList::<(i32, char), 5>::new();
+        for pair in [(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')] {
+            list.insert(pair);
+        }
+
+        list.sort_by_key(|pair| pair.0);
+        assert_eq!(
+            list.iter().map(|pair| pair.1).collect::<String>(),
+            "ebdac"
+        );
+    }
+
+    #[test]
+    fn test_sort_matches_vec_static() {
+        for size in 0..40 {
+            let mut list = StaticLinkedList::<usize, 40>::new();
+            let mut expected = Vec::new();
+            for i in 0..size {
+                let value = (i * 7919) % 13;
+                list.insert(value);
+                expected.push(value);
+            }
 
+            list.sort();
+            expected.sort();
+            assert_eq!(list.iter().copied().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    // A comparator that panics partway through must leave every occupied slot linked,
+    // with a tail and length that still match the list, so no capacity is lost.
+    #[test]
+    fn test_sort_by_panic_keeps_list_whole_static() {
+        let mut list = StaticLinkedList::<i32, 10>::new();
+        for value in [5, 3, 9, 1, 4, 8, 2, 7, 6] {
+            list.insert(value);
+        }
 
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            list.sort_by(|a, b| {
+                calls += 1;
+                if calls == 10 {
+                    panic!("comparator failed");
+                }
+                a.cmp(b)
+            });
+        }));
+        assert!(result.is_err());
+        assert_eq!(list.len(), 9);
+        assert!(list.validate().is_ok());
+
+        list.insert(42);
+        assert!(list.is_full());
+        assert_eq!(list.get(9), Some(42));
+        let mut values = list.iter().copied().collect::<Vec<_>>();
+        values.sort();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 42]);
+    }
+
+    #[test]
+    fn test_reverse_static() {
+        let mut list = StaticLinkedList::<i32, 5>::new();
+        for i in 1..=4 {
+            list.insert(i);
+        }
+
+        list.reverse();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4, 3, 2, 1]);
+        list.insert(0); // tail is the old head
+        assert_eq!(list.get(4), Some(0));
+    }
 
-    
+    #[test]
+    fn test_rotate_static() {
+        let mut list = StaticLinkedList::<i32, 6>::new();
+        for i in 0..5 {
+            list.insert(i);
+        }
+
+        list.rotate_left(2);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![2, 3, 4, 0, 1]);
+        list.rotate_right(7); // same as rotating right by 2
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![0, 1, 2, 3, 4]);
+        list.rotate_right(1);
+        list.insert(5);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![4, 0, 1, 2, 3, 5]);
+    }
+
+    #[test]
+    fn test_dedup_static() {
+        let mut list = StaticLinkedList::<i32, 9>::new();
+        for value in [1, 1, 2, 3, 3, 3, 1, 4, 4] {
+            list.insert(value);
+        }
+
+        list.dedup();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2, 3, 1, 4]);
+        for value in 5..9 {
+            list.insert(value); // removed slots went back to the free list
+        }
+        assert_eq!(list.len(), 9);
+        assert_eq!(list.get(8), Some(8));
+
+        list.dedup_by_key(|value| *value / 4);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 4, 8]);
+    }
+
+    #[test]
+    fn test_swap_static() {
+        let mut list = StaticLinkedList::<i32, 4>::new();
+        for i in 0..4 {
+            list.insert(i);
+        }
+
+        assert!(list.swap(0, 3));
+        assert!(list.swap(2, 1));
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0]);
+        assert!(!list.swap(4, 0)); // out of bounds
+    }
+
+    #[test]
+    fn test_nth_from_back_and_middle_static() {
+        let mut list = StaticLinkedList::<i32, 6>::new();
+        assert_eq!(list.nth_from_back(0), None);
+        assert_eq!(list.middle(), None);
+
+        for i in 0..5 {
+            list.insert(i);
+        }
+        assert_eq!(list.nth_from_back(1), Some(&3));
+        assert_eq!(list.nth_from_back(5), None);
+        assert_eq!(list.middle(), Some(&2));
+
+        list.insert(5);
+        assert_eq!(list.middle(), Some(&3));
+        assert!(!list.has_cycle());
+    }
 }
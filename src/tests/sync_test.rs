@@ -0,0 +1,216 @@
+#[cfg(test)]
+mod sync_tests {
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Barrier, Mutex};
+    use std::thread;
+
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::sync_list::SyncLinkedList;
+    use crate::tests::Rng;
+
+    const THREADS: usize = 8;
+    const OPS_PER_THREAD: usize = 5_000;
+
+    #[test]
+    fn test_sequential_operations_match_dynamic_list() {
+        let list = SyncLinkedList::new();
+        let mut baseline = DynamicLinkedList::new();
+        assert!(list.is_empty());
+
+        for value in [1, 3, 5] {
+            list.insert(value);
+            baseline.insert(value);
+        }
+        list.insert_at_index(1, 2);
+        baseline.insert_at_index(1, 2);
+        list.insert_at_index(4, 6);
+        baseline.insert_at_index(4, 6);
+        // Out of bounds, so nothing is inserted.
+        list.insert_at_index(9, 7);
+        baseline.insert_at_index(9, 7);
+        assert_eq!(list.to_vec(), vec![1, 2, 3, 5, 6]);
+        assert_eq!(list.to_vec(), baseline.iter().copied().collect::<Vec<_>>());
+
+        assert_eq!(list.find(3), baseline.find(3));
+        assert_eq!(list.find(4), baseline.find(4));
+        assert_eq!(list.update_element(3, 4), baseline.update_element(3, 4));
+        assert_eq!(list.update_element(9, 0), baseline.update_element(9, 0));
+        assert_eq!(list.delete_element(1), baseline.delete_element(1));
+        assert_eq!(list.delete_element(1), baseline.delete_element(1));
+        assert_eq!(list.delete_at_index(3), baseline.delete_at_index(3));
+        assert_eq!(list.delete_at_index(3), baseline.delete_at_index(3));
+        assert_eq!(
+            list.update_element_at_index(0, 8),
+            baseline.update_element_at_index(0, 8)
+        );
+        assert_eq!(list.get(0), baseline.get(0));
+        assert_eq!(list.get(5), baseline.get(5));
+
+        assert_eq!(list.to_vec(), vec![8, 4, 5]);
+        assert_eq!(list.len(), baseline.len());
+        assert_eq!(list.iter().collect::<Vec<_>>(), vec![8, 4, 5]);
+        assert_eq!(format!("{list:?}"), "[8, 4, 5]");
+    }
+
+    #[test]
+    fn test_insert_at_index_into_empty_list() {
+        let list = SyncLinkedList::new();
+        list.insert_at_index(1, 1);
+        assert!(list.is_empty());
+        list.insert_at_index(0, 1);
+        assert_eq!(list.to_vec(), vec![1]);
+    }
+
+    // Each thread owns the values congruent to its index and applies every operation to the
+    // locked list and to a baseline behind one Mutex. The results depend only on the thread's
+    // own values, so they must agree, and so must the final contents once sorted.
+    #[test]
+    fn test_stress_matches_single_mutex_baseline() {
+        let list = SyncLinkedList::new();
+        let baseline = Mutex::new(DynamicLinkedList::new());
+        let barrier = Barrier::new(THREADS);
+
+        thread::scope(|scope| {
+            for t in 0..THREADS {
+                let (list, baseline, barrier) = (&list, &baseline, &barrier);
+                scope.spawn(move || {
+                    let mut rng = Rng::new(0x9E37_79B9 + t as u64);
+                    let value = |rng: &mut Rng| rng.below(16) * THREADS as u64 + t as u64;
+                    barrier.wait();
+                    for _ in 0..OPS_PER_THREAD {
+                        let data = value(&mut rng);
+                        match rng.below(5) {
+                            0 => {
+                                list.insert(data);
+                                baseline.lock().unwrap().insert(data);
+                            }
+                            1 => {
+                                list.insert_at_index(0, data);
+                                baseline.lock().unwrap().insert_at_index(0, data);
+                            }
+                            2 => assert_eq!(
+                                list.delete_element(data),
+                                baseline.lock().unwrap().delete_element(data)
+                            ),
+                            3 => {
+                                let new_data = value(&mut rng);
+                                assert_eq!(
+                                    list.update_element(data, new_data),
+                                    baseline.lock().unwrap().update_element(data, new_data)
+                                );
+                            }
+                            _ => assert_eq!(list.find(data), baseline.lock().unwrap().find(data)),
+                        }
+                    }
+                });
+            }
+        });
+
+        let mut actual = list.to_vec();
+        let mut expected: Vec<u64> = baseline.into_inner().unwrap().iter().copied().collect();
+        actual.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(actual, expected);
+        assert_eq!(list.len(), expected.len());
+    }
+
+    // Readers iterate over and over while writers insert and delete at both ends of the list.
+    // Nothing may deadlock, and every snapshot a reader sees must only contain written values.
+    #[test]
+    fn test_iteration_alongside_writers() {
+        let list = SyncLinkedList::new();
+        for value in 0..100u64 {
+            list.insert(value);
+        }
+        let writing = AtomicBool::new(true);
+
+        let added: usize = thread::scope(|scope| {
+            let readers: Vec<_> = (0..THREADS / 2)
+                .map(|_| {
+                    scope.spawn(|| {
+                        let mut passes = 0;
+                        while writing.load(Ordering::Relaxed) || passes == 0 {
+                            assert!(list.iter().all(|value| value < 1_000));
+                            list.for_each(|value| assert!(*value < 1_000));
+                            passes += 1;
+                        }
+                    })
+                })
+                .collect();
+
+            let writers: Vec<_> = (0..THREADS / 2)
+                .map(|t| {
+                    let list = &list;
+                    scope.spawn(move || {
+                        let mut rng = Rng::new(0xDEAD_BEEF + t as u64);
+                        let mut added = 0;
+                        for _ in 0..OPS_PER_THREAD / 5 {
+                            let value = 100 + rng.below(900);
+                            // Another writer may have removed value from the front first.
+                            let removed = if rng.next_u64().is_multiple_of(2) {
+                                list.insert_at_index(0, value);
+                                list.delete_element(value)
+                            } else {
+                                list.insert(value);
+                                list.delete_at_index(0)
+                            };
+                            added += !removed as usize;
+                        }
+                        added
+                    })
+                })
+                .collect();
+
+            let added = writers.into_iter().map(|w| w.join().unwrap()).sum();
+            writing.store(false, Ordering::Relaxed);
+            for reader in readers {
+                reader.join().unwrap();
+            }
+            added
+        });
+
+        // Every writer step adds one element and removes one unless it found nothing to remove.
+        assert_eq!(list.len(), 100 + added);
+        assert_eq!(list.to_vec().len(), list.len());
+    }
+
+    // While f runs only the current node is locked, so another thread can update the
+    // element before it in the meantime.
+    #[test]
+    fn test_for_each_holds_only_the_current_node() {
+        let list = SyncLinkedList::new();
+        for value in [0, 1, 2] {
+            list.insert(value);
+        }
+
+        let (done_tx, done_rx) = std::sync::mpsc::channel();
+        let mut seen = Vec::new();
+        thread::scope(|scope| {
+            list.for_each(|value| {
+                seen.push(*value);
+                if *value == 1 {
+                    let done_tx = done_tx.clone();
+                    let list = &list;
+                    scope.spawn(move || {
+                        list.update_element(0, 10);
+                        done_tx.send(()).unwrap();
+                    });
+                    let updated = done_rx.recv_timeout(std::time::Duration::from_secs(5));
+                    assert!(updated.is_ok(), "the previous node was still locked");
+                }
+            });
+        });
+        assert_eq!(seen, vec![0, 1, 2]);
+        assert_eq!(list.to_vec(), vec![10, 1, 2]);
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let list = SyncLinkedList::new();
+        for value in 0..200_000 {
+            list.insert_at_index(0, value);
+        }
+        assert_eq!(list.len(), 200_000);
+        drop(list);
+    }
+}
@@ -0,0 +1,189 @@
+#[cfg(test)]
+mod undo_tests {
+    use crate::tests::Rng;
+    use crate::undo_list::{RollbackError, StaticUndoableList, UndoableList};
+
+    fn contents<L: crate::storage::ListStorage<i32>>(list: &UndoableList<i32, L>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_undo_and_redo_each_edit() {
+        let mut list: UndoableList<i32> = UndoableList::new();
+        assert!(!list.undo());
+        list.insert(1).unwrap();
+        list.insert(3).unwrap();
+        list.insert_at_index(1, 2).unwrap();
+        assert_eq!(list.insert_at_index(9, 9), Err(9));
+        assert!(list.update_element(3, 30));
+        assert!(list.update_element_at_index(0, 10));
+        assert!(list.delete_element(2));
+        assert!(list.delete_at_index(1));
+        assert!(!list.delete_at_index(5));
+        assert_eq!(contents(&list), vec![10]);
+
+        let mut states = Vec::new();
+        while list.can_undo() {
+            list.undo();
+            states.push(contents(&list));
+        }
+        assert_eq!(
+            states,
+            vec![
+                vec![10, 30],
+                vec![10, 2, 30],
+                vec![1, 2, 30],
+                vec![1, 2, 3],
+                vec![1, 3],
+                vec![1],
+                vec![],
+            ]
+        );
+
+        assert!(list.redo());
+        assert!(list.redo());
+        assert_eq!(contents(&list), vec![1, 3]);
+        assert!(list.find(3));
+        assert_eq!(list.get(1), Some(&3));
+    }
+
+    #[test]
+    fn test_new_edit_discards_redo() {
+        let mut list: UndoableList<i32> = UndoableList::new();
+        list.insert(1).unwrap();
+        list.insert(2).unwrap();
+        list.undo();
+        assert!(list.can_redo());
+        list.insert(3).unwrap();
+        assert!(!list.can_redo());
+        assert!(!list.redo());
+        assert_eq!(contents(&list), vec![1, 3]);
+    }
+
+    #[test]
+    fn test_checkpoints() {
+        let mut list: UndoableList<i32> = UndoableList::new();
+        let empty = list.checkpoint();
+        list.insert(1).unwrap();
+        list.insert(2).unwrap();
+        let two = list.checkpoint();
+        list.insert(3).unwrap();
+        list.update_element_at_index(0, 10);
+        let last = list.checkpoint();
+
+        list.rollback_to(two).unwrap();
+        assert_eq!(contents(&list), vec![1, 2]);
+        list.rollback_to(empty).unwrap();
+        assert!(list.is_empty());
+        // Going forward again replays the undone edits.
+        list.rollback_to(last).unwrap();
+        assert_eq!(contents(&list), vec![10, 2, 3]);
+        list.rollback_to(last).unwrap();
+
+        // A new edit after rolling back drops the checkpoints it branched away from.
+        list.rollback_to(two).unwrap();
+        list.delete_at_index(0);
+        assert_eq!(list.rollback_to(last), Err(RollbackError));
+        assert_eq!(contents(&list), vec![2]);
+        list.rollback_to(two).unwrap();
+        assert_eq!(contents(&list), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_history_limit() {
+        let mut list: UndoableList<i32> = UndoableList::with_history_limit(2);
+        let start = list.checkpoint();
+        for value in 1..=4 {
+            list.insert(value).unwrap();
+        }
+        assert!(list.undo());
+        assert!(list.undo());
+        assert!(!list.undo());
+        assert_eq!(contents(&list), vec![1, 2]);
+        assert_eq!(list.rollback_to(start), Err(RollbackError));
+
+        list.redo();
+        let checkpoint = list.checkpoint();
+        list.clear_history();
+        assert!(!list.can_redo());
+        assert_eq!(list.rollback_to(checkpoint), Ok(()));
+        assert_eq!(list.rollback_to(start), Err(RollbackError));
+
+        let mut none: UndoableList<i32> = UndoableList::with_history_limit(0);
+        none.insert(1).unwrap();
+        assert!(!none.undo());
+    }
+
+    // Rolling back a fixed size list gives back exactly the slots the undone edits took,
+    // however full it got in between.
+    #[test]
+    fn test_static_rollback_restores_capacity() {
+        let mut list: StaticUndoableList<i32, 4> = StaticUndoableList::new();
+        list.insert(1).unwrap();
+        list.insert(2).unwrap();
+        let checkpoint = list.checkpoint();
+
+        list.insert(3).unwrap();
+        list.insert_at_index(0, 0).unwrap();
+        assert_eq!(list.insert(5), Err(5));
+        assert!(list.delete_element(1));
+        assert!(list.update_element(2, 20));
+        list.insert(4).unwrap();
+        assert!(list.list().is_full());
+
+        list.rollback_to(checkpoint).unwrap();
+        assert_eq!(contents(&list), vec![1, 2]);
+        let free = list
+            .list()
+            .slots()
+            .filter(|(data, _)| data.is_none())
+            .count();
+        assert_eq!(free, 2);
+        assert!(list.list().validate().is_ok());
+
+        // Redoing everything needs the whole array again, and it is all there.
+        while list.redo() {}
+        assert_eq!(contents(&list), vec![0, 20, 3, 4]);
+        assert!(list.list().is_full());
+
+        list.rollback_to(checkpoint).unwrap();
+        for value in [7, 8] {
+            list.insert(value).unwrap();
+        }
+        assert_eq!(list.insert(9), Err(9));
+        list.undo();
+        list.undo();
+        assert_eq!(list.list().capacity() - list.len(), 2);
+    }
+
+    // Random edits with checkpoints, checked by rolling back to each and comparing with
+    // a copy of the contents taken when the checkpoint was.
+    #[test]
+    fn test_rollback_matches_saved_contents() {
+        let mut list: StaticUndoableList<i32, 16> = StaticUndoableList::new();
+        let mut saved = vec![(list.checkpoint(), Vec::new())];
+        let mut rng = Rng::new(0x1234_5678);
+        for value in 0..500 {
+            let index = rng.below(list.len() as u64 + 1) as usize;
+            match rng.below(4) {
+                0 | 1 => {
+                    let _ = list.insert_at_index(index, value);
+                }
+                2 => {
+                    list.delete_at_index(index);
+                }
+                _ => {
+                    list.update_element_at_index(index, value);
+                }
+            }
+            if value % 25 == 0 {
+                saved.push((list.checkpoint(), contents(&list)));
+            }
+        }
+        for (checkpoint, expected) in saved.iter().rev().chain(saved.iter()) {
+            list.rollback_to(*checkpoint).unwrap();
+            assert_eq!(&contents(&list), expected);
+            assert_eq!(list.list().capacity() - list.len(), 16 - expected.len());
+        }
+    }
+}
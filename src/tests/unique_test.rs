@@ -0,0 +1,87 @@
+#[cfg(test)]
+mod unique_tests {
+    use crate::dynamic_list::DynamicLinkedList;
+    use crate::sorted_list::InsertError;
+    use crate::unique_list::{StaticUniqueList, UniqueList};
+
+    #[test]
+    fn test_dynamic_insert_keeps_duplicates() {
+        // DynamicLinkedList::insert appends unconditionally; UniqueList is the no-duplicates mode
+        let mut list = DynamicLinkedList::new();
+        list.insert(1);
+        list.insert(1);
+
+        assert_eq!(list.len(), 2);
+        assert_eq!(list.get(1), Some(1));
+    }
+
+    #[test]
+    fn test_insert_rejects_duplicates() {
+        let mut list: UniqueList<i32> = UniqueList::new();
+
+        assert!(list.insert(1));
+        assert!(list.insert(2));
+        assert!(!list.insert(1)); // already present, not inserted again
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(list.try_insert(2), Err(InsertError::Duplicate(2)));
+    }
+
+    #[test]
+    fn test_insert_or_replace() {
+        let mut list: UniqueList<&str> = UniqueList::new();
+        list.insert("a");
+        list.insert("b");
+
+        assert_eq!(list.insert_or_replace("b"), Ok(Some("b")));
+        assert_eq!(list.insert_or_replace("c"), Ok(None));
+        assert_eq!(
+            list.iter().copied().collect::<Vec<_>>(),
+            vec!["a", "b", "c"]
+        );
+    }
+
+    #[test]
+    fn test_remove_and_contains() {
+        let mut list: UniqueList<i32> = UniqueList::new();
+        list.insert(1);
+        list.insert(2);
+
+        assert!(list.contains(&2));
+        assert_eq!(list.remove(&2), Some(2));
+        assert!(!list.contains(&2));
+        assert_eq!(list.remove(&2), None);
+        assert!(list.insert(2)); // can be added again once removed
+    }
+
+    #[test]
+    fn test_static_unique_list() {
+        let mut list: StaticUniqueList<i32, 2> = UniqueList::new();
+
+        assert!(list.insert(1));
+        assert!(!list.insert(1));
+        assert!(list.insert(2));
+        assert_eq!(list.try_insert(3), Err(InsertError::Full(3)));
+        assert_eq!(list.try_insert(2), Err(InsertError::Duplicate(2)));
+        assert_eq!(list.insert_or_replace(1), Ok(Some(1)));
+        assert_eq!(list.len(), 2);
+    }
+
+    #[cfg(feature = "hash-index")]
+    #[test]
+    fn test_hashed_unique_list() {
+        use crate::unique_list::HashedUniqueList;
+
+        let mut list: HashedUniqueList<String> = UniqueList::new();
+        assert!(list.insert("a".to_string()));
+        assert!(!list.insert("a".to_string()));
+        assert!(list.contains(&"a".to_string()));
+
+        assert_eq!(list.remove(&"a".to_string()), Some("a".to_string()));
+        assert!(!list.contains(&"a".to_string()));
+        assert!(list.insert("a".to_string()));
+
+        list.clear();
+        assert!(!list.contains(&"a".to_string()));
+        assert!(list.is_empty());
+    }
+}
@@ -0,0 +1,133 @@
+#[cfg(test)]
+mod unrolled_tests {
+    use std::rc::Rc;
+
+    use crate::tests::Rng;
+    use crate::unrolled_list::UnrolledList;
+
+    #[test]
+    fn test_existing_method_set() {
+        let mut list: UnrolledList<i32, 4> = UnrolledList::new();
+        assert!(list.is_empty());
+        list.insert(1);
+        list.insert(3);
+        list.insert_at_index(1, 2);
+        list.insert_at_index(0, 0);
+        list.insert_at_index(10, 99); // Out of bounds
+        assert_eq!(list.len(), 4);
+        assert_eq!(list.get(0), Some(0));
+        assert_eq!(list.get(3), Some(3));
+        assert_eq!(list.get(4), None);
+        assert!(list.find(2));
+        assert!(!list.find(99));
+
+        assert!(list.update_element(2, 20));
+        assert!(!list.update_element(7, 70));
+        assert!(list.update_element_at_index(0, 10));
+        assert!(!list.update_element_at_index(4, 0));
+        assert!(list.delete_element(3));
+        assert!(!list.delete_element(3));
+        assert!(list.delete_at_index(0));
+        assert!(!list.delete_at_index(2));
+        assert_eq!(format!("{list:?}"), "[1, 20]");
+
+        list.clear();
+        assert!(list.is_empty());
+        list.push_front(5);
+        list.insert(6);
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![5, 6]);
+    }
+
+    // Random inserts, appends, removes and updates, checked against a Vec after each step.
+    // Small nodes make splits, merges and borrows between nodes happen often.
+    fn check_against_vec<const B: usize>() {
+        for seed in 0..4u64 {
+            let mut list: UnrolledList<u64, B> = UnrolledList::new();
+            let mut model = Vec::new();
+            let mut rng = Rng::new(0x9E37_79B9 + seed);
+            for step in 0..3_000 {
+                let index = rng.below(model.len() as u64 + 1) as usize;
+                match rng.below(5) {
+                    0 | 1 => {
+                        list.insert_at_index(index, step);
+                        model.insert(index, step);
+                    }
+                    2 => {
+                        list.push_back(step);
+                        model.push(step);
+                    }
+                    3 => {
+                        let expected = (index < model.len()).then(|| model.remove(index));
+                        assert_eq!(list.remove(index), expected);
+                    }
+                    _ => {
+                        let updated = index < model.len();
+                        if updated {
+                            model[index] = step;
+                        }
+                        assert_eq!(list.update_element_at_index(index, step), updated);
+                    }
+                }
+                assert_eq!(list.len(), model.len());
+                let probe = rng.below(model.len() as u64 + 1) as usize;
+                assert_eq!(list.get(probe), model.get(probe).copied(), "step {step}");
+            }
+            assert!(list.iter().eq(model.iter()));
+            while let Some(value) = model.pop() {
+                assert_eq!(list.remove(model.len()), Some(value));
+            }
+            assert!(list.is_empty());
+            list.push_back(1);
+            assert_eq!(list.get(0), Some(1));
+        }
+    }
+
+    #[test]
+    fn test_matches_vec_model() {
+        check_against_vec::<2>();
+        check_against_vec::<3>();
+        check_against_vec::<8>();
+    }
+
+    #[test]
+    fn test_iterators() {
+        let mut list: UnrolledList<i32, 3> = (1..=10).collect();
+        assert_eq!(list.iter().len(), 10);
+        for value in &mut list {
+            *value *= 2;
+        }
+        assert_eq!((&list).into_iter().sum::<i32>(), 110);
+
+        let mut owned = list.into_iter();
+        assert_eq!(owned.len(), 10);
+        assert_eq!(owned.next(), Some(2));
+        assert_eq!(owned.nth(3), Some(10));
+        assert_eq!(owned.collect::<Vec<_>>(), vec![12, 14, 16, 18, 20]);
+    }
+
+    // Dropping the list or a part-used owning iterator drops every element exactly once.
+    #[test]
+    fn test_drop_releases_elements() {
+        let marker = Rc::new(());
+        let list: UnrolledList<Rc<()>, 4> = (0..50).map(|_| Rc::clone(&marker)).collect();
+        let mut owned = list.into_iter();
+        owned.next();
+        owned.next();
+        drop(owned);
+        assert_eq!(Rc::strong_count(&marker), 1);
+
+        let mut list: UnrolledList<Rc<()>, 4> = UnrolledList::new();
+        for index in 0..50 {
+            list.insert_at_index(index / 2, Rc::clone(&marker));
+        }
+        drop(list);
+        assert_eq!(Rc::strong_count(&marker), 1);
+    }
+
+    #[test]
+    fn test_drop_long_list() {
+        let list: UnrolledList<u8, 2> = std::iter::repeat_n(0, 1_000_000).collect();
+        assert_eq!(list.len(), 1_000_000);
+        drop(list);
+    }
+}
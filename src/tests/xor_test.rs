@@ -0,0 +1,186 @@
+#[cfg(test)]
+mod xor_tests {
+    use std::collections::VecDeque;
+    use std::mem::size_of;
+
+    use crate::adapters::ListDeque;
+    use crate::static_list::StaticNode;
+    use crate::tests::Rng;
+    use crate::xor_list::{XorLinkedList, XorNode};
+
+    fn contents<const N: usize>(list: &XorLinkedList<i32, N, u8>) -> Vec<i32> {
+        list.iter().copied().collect()
+    }
+
+    #[test]
+    fn test_slots_are_no_larger_than_singly_linked() {
+        assert!(size_of::<XorNode<u64>>() <= size_of::<StaticNode<u64>>());
+        assert!(size_of::<XorNode<u32, u16>>() < size_of::<StaticNode<u32>>());
+    }
+
+    #[test]
+    fn test_push_and_pop_at_both_ends() {
+        let mut list: XorLinkedList<i32, 4> = XorLinkedList::new();
+        assert_eq!(list.pop_front(), None);
+        assert_eq!(list.pop_back(), None);
+        list.push_back(2).unwrap();
+        list.push_front(1).unwrap();
+        list.push_back(3).unwrap();
+        list.push_front(0).unwrap();
+        assert_eq!(list.push_back(4), Err(4));
+        assert!(list.is_full());
+        assert_eq!((list.front(), list.back()), (Some(&0), Some(&3)));
+        assert_eq!(format!("{list:?}"), "[0, 1, 2, 3]");
+
+        assert_eq!(list.pop_back(), Some(3));
+        assert_eq!(list.pop_front(), Some(0));
+        list.push_front(5).unwrap();
+        assert_eq!(list.iter().copied().collect::<Vec<_>>(), vec![5, 1, 2]);
+        list.clear();
+        assert!(list.is_empty());
+        assert_eq!(list.back(), None);
+    }
+
+    #[test]
+    fn test_forward_and_reverse_iteration() {
+        let list: XorLinkedList<i32, 8> = (1..=6).collect();
+        assert!(list.iter().eq(&[1, 2, 3, 4, 5, 6]));
+        assert!(list.iter().rev().eq(&[6, 5, 4, 3, 2, 1]));
+        assert_eq!(list.iter().len(), 6);
+        assert!(list.contains(&4));
+
+        // The two ends meet in the middle without yielding anything twice.
+        let mut iter = list.iter();
+        assert_eq!(iter.next(), Some(&1));
+        assert_eq!(iter.next_back(), Some(&6));
+        assert_eq!(iter.next_back(), Some(&5));
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.copied().collect::<Vec<_>>(), vec![2, 3, 4]);
+
+        for index in 0..6 {
+            assert_eq!(list.get(index), Some(&(index as i32 + 1)));
+        }
+        assert_eq!(list.get(6), None);
+    }
+
+    #[test]
+    fn test_cursor_moves_through_ghost() {
+        let mut list: XorLinkedList<i32, 4, u8> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&1)));
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current()), (None, None));
+        cursor.move_prev();
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&3)));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&2));
+        cursor.move_next();
+        cursor.move_next();
+        assert_eq!(cursor.current(), None);
+        cursor.move_next();
+        assert_eq!((cursor.index(), cursor.current()), (Some(0), Some(&1)));
+
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.current(), Some(&3));
+        *cursor.current_mut().unwrap() = 30;
+        assert_eq!(contents(&list), vec![1, 2, 30]);
+    }
+
+    #[test]
+    fn test_cursor_insert_and_remove() {
+        let mut list: XorLinkedList<i32, 8, u8> = (1..=3).collect();
+        let mut cursor = list.cursor_front_mut();
+        cursor.move_next();
+        cursor.insert_before(10).unwrap();
+        cursor.insert_after(20).unwrap();
+        assert_eq!((cursor.index(), cursor.current()), (Some(2), Some(&2)));
+        assert_eq!(cursor.remove_current(), Some(2));
+        assert_eq!(cursor.current(), Some(&20));
+        cursor.move_prev();
+        assert_eq!(cursor.current(), Some(&10));
+        assert_eq!(contents(&list), vec![1, 10, 20, 3]);
+
+        // Removing the tail leaves the cursor on the ghost, where inserts go to the ends.
+        let mut cursor = list.cursor_back_mut();
+        assert_eq!(cursor.remove_current(), Some(3));
+        assert_eq!(cursor.current(), None);
+        assert_eq!(cursor.remove_current(), None);
+        cursor.insert_before(4).unwrap();
+        cursor.insert_after(0).unwrap();
+        assert!(list.iter().eq(&[0, 1, 10, 20, 4]));
+        assert!(list.iter().rev().eq(&[4, 20, 10, 1, 0]));
+    }
+
+    #[test]
+    fn test_full_list_rejects_cursor_inserts() {
+        let mut list: XorLinkedList<i32, 2, u8> = (1..=2).collect();
+        let mut cursor = list.cursor_front_mut();
+        assert_eq!(cursor.insert_after(3), Err(3));
+        assert_eq!(cursor.insert_before(3), Err(3));
+        assert_eq!(cursor.remove_current(), Some(1));
+        cursor.insert_before(3).unwrap();
+        assert_eq!(contents(&list), vec![3, 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "too narrow")]
+    fn test_index_type_too_narrow() {
+        let _list: XorLinkedList<i32, 256, u8> = XorLinkedList::new();
+    }
+
+    // Random operations from both ends and through a cursor, checked against a VecDeque.
+    #[test]
+    fn test_matches_vec_deque_model() {
+        let mut list: XorLinkedList<u64, 64, u8> = XorLinkedList::new();
+        let mut model = VecDeque::new();
+        let mut rng = Rng::new(0x2545_F491);
+        for step in 0..5_000 {
+            let index = rng.below(model.len() as u64 + 1) as usize;
+            match rng.below(6) {
+                0 => match list.push_front(step) {
+                    Ok(()) => model.push_front(step),
+                    Err(_) => assert_eq!(model.len(), 64),
+                },
+                1 => match list.push_back(step) {
+                    Ok(()) => model.push_back(step),
+                    Err(_) => assert_eq!(model.len(), 64),
+                },
+                2 => assert_eq!(list.pop_front(), model.pop_front()),
+                3 => assert_eq!(list.pop_back(), model.pop_back()),
+                4 => {
+                    let mut cursor = list.cursor_front_mut();
+                    for _ in 0..index {
+                        cursor.move_next();
+                    }
+                    if cursor.insert_before(step).is_ok() {
+                        model.insert(index, step);
+                    }
+                }
+                _ => {
+                    // From the ghost, so an index one past the tail removes nothing.
+                    let mut cursor = list.cursor_back_mut();
+                    cursor.move_next();
+                    for _ in index..model.len() {
+                        cursor.move_prev();
+                    }
+                    assert_eq!(cursor.remove_current(), model.remove(index));
+                }
+            }
+            assert_eq!(list.len(), model.len());
+            assert!(list.iter().eq(model.iter()), "step {step}");
+            assert!(list.iter().rev().eq(model.iter().rev()), "step {step}");
+        }
+    }
+
+    #[test]
+    fn test_deque_adapter_over_xor_list() {
+        let mut deque: ListDeque<i32, XorLinkedList<i32, 3>> = ListDeque::new();
+        deque.push_back(2).unwrap();
+        deque.push_front(1).unwrap();
+        deque.push_back(3).unwrap();
+        assert!(deque.push_back(4).is_err());
+        assert_eq!(deque.pop_back(), Some(3));
+        assert_eq!(deque.pop_front(), Some(1));
+        assert_eq!(deque.peek_back(), Some(&2));
+    }
+}
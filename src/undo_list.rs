@@ -0,0 +1,315 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::marker::PhantomData;
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::static_list::StaticLinkedList;
+use crate::storage::ListStorage;
+
+// One change to a list, recorded as the change that reverses it.
+// Applying an edit gives back the edit that reverses it in turn.
+enum Edit<T> {
+    // Put data at index.
+    Insert(usize, T),
+    // Take out the element at index.
+    Remove(usize),
+    // Swap the element at index for data.
+    Replace(usize, T),
+}
+
+impl<T> Edit<T> {
+    // Applies the edit and returns its inverse. The edits in a history always fit the state they
+    // are applied to, including a fixed size list's free slots, so failing here is a bug.
+    fn apply<L: ListStorage<T>>(self, list: &mut L) -> Edit<T> {
+        match self {
+            Edit::Insert(index, data) => {
+                if list.insert_at(index, data).is_err() {
+                    panic!("undo history does not match the list");
+                }
+                Edit::Remove(index)
+            }
+            Edit::Remove(index) => {
+                let data = list.remove_at(index);
+                Edit::Insert(index, data.expect("undo history does not match the list"))
+            }
+            Edit::Replace(index, data) => {
+                let slot = list.get_mut(index);
+                let old =
+                    std::mem::replace(slot.expect("undo history does not match the list"), data);
+                Edit::Replace(index, old)
+            }
+        }
+    }
+}
+
+// A point in an UndoableList's history that rollback_to can return to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Checkpoint(u64);
+
+// Error returned when rolling back to a checkpoint the history no longer reaches, because it
+// fell off a bounded history or was on a line of redo entries discarded by a new edit.
+#[derive(Debug, PartialEq)]
+pub struct RollbackError;
+
+impl fmt::Display for RollbackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "the checkpoint is no longer in the history")
+    }
+}
+
+impl std::error::Error for RollbackError {}
+
+// A list that records the inverse of every change made through it, so changes can be undone
+// and redone one at a time or rolled back to a checkpoint.
+// The history holds removed and replaced elements itself, outside the list, so undoing
+// and redoing on a fixed size list only ever needs the slots the change originally used.
+// Every edit has an id; a checkpoint is the id of the last edit applied when it was taken.
+// T: The type of data stored in the list.
+// L: The list the elements live in, a DynamicLinkedList by default.
+pub struct UndoableList<T, L: ListStorage<T> = DynamicLinkedList<T>> {
+    list: L,
+    // Inverses of the applied edits, oldest first, with each edit's id.
+    undo: VecDeque<(u64, Edit<T>)>,
+    // Inverses of the undone edits, most recently undone last, with each edit's id.
+    redo: Vec<(u64, Edit<T>)>,
+    // The id of the state below the oldest undo entry: 0 at first, or the last dropped edit.
+    base: u64,
+    next_id: u64,
+    // The most undo entries kept, or None for no limit.
+    limit: Option<usize>,
+    marker: PhantomData<T>,
+}
+
+// An UndoableList over a fixed size StaticLinkedList.
+pub type StaticUndoableList<T, const N: usize> = UndoableList<T, StaticLinkedList<T, N>>;
+
+impl<T, L: ListStorage<T>> UndoableList<T, L> {
+    // Creates an empty list with an unbounded history.
+    pub fn new() -> Self {
+        UndoableList {
+            list: L::default(),
+            undo: VecDeque::new(),
+            redo: Vec::new(),
+            base: 0,
+            next_id: 1,
+            limit: None,
+            marker: PhantomData,
+        }
+    }
+
+    // Creates an empty list that keeps at most limit edits to undo, forgetting the oldest first.
+    // limit: usize: The most edits that can be undone.
+    pub fn with_history_limit(limit: usize) -> Self {
+        UndoableList {
+            limit: Some(limit),
+            ..Self::new()
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.list.len()
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.list.is_empty()
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    pub fn iter(&self) -> L::Iter<'_> {
+        self.list.iter()
+    }
+
+    // Returns a reference to the element at index, or None if it is out of bounds.
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.list.iter().nth(index)
+    }
+
+    // Returns the underlying list.
+    pub fn list(&self) -> &L {
+        &self.list
+    }
+
+    // Returns the underlying list, dropping the history.
+    pub fn into_inner(self) -> L {
+        self.list
+    }
+
+    // Inserts a new element at the end of the list.
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn insert(&mut self, data: T) -> Result<(), T> {
+        let index = self.list.len();
+        self.list.push_back(data)?;
+        self.record(Edit::Remove(index));
+        Ok(())
+    }
+
+    // Inserts a new element at the specified index in the list.
+    // Returns the data back if the index is out of bounds or there is no space available.
+    // index: usize: The position the new element will occupy.
+    // data: T: The element to insert.
+    pub fn insert_at_index(&mut self, index: usize, data: T) -> Result<(), T> {
+        self.list.insert_at(index, data)?;
+        self.record(Edit::Remove(index));
+        Ok(())
+    }
+
+    // Deletes the element at the specified index from the list.
+    // Returns true if the element was found and deleted, false otherwise.
+    // index: usize: The position of the element to delete.
+    pub fn delete_at_index(&mut self, index: usize) -> bool {
+        match self.list.remove_at(index) {
+            Some(data) => {
+                self.record(Edit::Insert(index, data));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Updates the element at the specified index with new data.
+    // Returns true if the element was found and updated, false otherwise.
+    // index: usize: The position of the element to update.
+    // data: T: The new element.
+    pub fn update_element_at_index(&mut self, index: usize, data: T) -> bool {
+        match self.list.get_mut(index) {
+            Some(slot) => {
+                let old = std::mem::replace(slot, data);
+                self.record(Edit::Replace(index, old));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns true if there is an edit to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    // Returns true if there is an undone edit to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    // Reverses the most recent edit that has not been undone.
+    // Returns true if there was one, false otherwise.
+    pub fn undo(&mut self) -> bool {
+        match self.undo.pop_back() {
+            Some((id, edit)) => {
+                self.redo.push((id, edit.apply(&mut self.list)));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Applies again the most recently undone edit.
+    // Returns true if there was one, false otherwise.
+    pub fn redo(&mut self) -> bool {
+        match self.redo.pop() {
+            Some((id, edit)) => {
+                self.undo.push_back((id, edit.apply(&mut self.list)));
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns a checkpoint for the list as it is now.
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint(self.current())
+    }
+
+    // Undoes or redoes edits until the list is as it was at the checkpoint.
+    // Returns a RollbackError, leaving the list unchanged, if the history no longer reaches it.
+    // checkpoint: Checkpoint: The state to return to.
+    pub fn rollback_to(&mut self, checkpoint: Checkpoint) -> Result<(), RollbackError> {
+        let Checkpoint(id) = checkpoint;
+        if id == self.base || self.undo.iter().any(|(entry_id, _)| *entry_id == id) {
+            while self.current() != id {
+                self.undo();
+            }
+            Ok(())
+        } else if self.redo.iter().any(|(entry_id, _)| *entry_id == id) {
+            while self.current() != id {
+                self.redo();
+            }
+            Ok(())
+        } else {
+            Err(RollbackError)
+        }
+    }
+
+    // Forgets every edit, so nothing can be undone or redone and older checkpoints are lost.
+    pub fn clear_history(&mut self) {
+        self.base = self.current();
+        self.undo.clear();
+        self.redo.clear();
+    }
+
+    // Returns the id of the last edit applied, or of the base state if there is none.
+    fn current(&self) -> u64 {
+        self.undo.back().map_or(self.base, |(id, _)| *id)
+    }
+
+    // Records the inverse of an edit just made, dropping the redo line it branches off from
+    // and the oldest entry if the history is over its limit.
+    fn record(&mut self, inverse: Edit<T>) {
+        self.redo.clear();
+        let id = self.next_id;
+        self.next_id += 1;
+        self.undo.push_back((id, inverse));
+        if self.limit.is_some_and(|limit| self.undo.len() > limit)
+            && let Some((dropped, _)) = self.undo.pop_front()
+        {
+            self.base = dropped;
+        }
+    }
+}
+
+impl<T: PartialEq, L: ListStorage<T>> UndoableList<T, L> {
+    // Deletes the first occurrence of the specified data from the list.
+    // Returns true if the element was found and deleted, false otherwise.
+    // data: T: The element to delete.
+    pub fn delete_element(&mut self, data: T) -> bool {
+        let position = self.list.iter().position(|item| *item == data);
+        match position {
+            Some(index) => self.delete_at_index(index),
+            None => false,
+        }
+    }
+
+    // Updates the first occurrence of old_data with new_data.
+    // Returns true if the element was found and updated, false otherwise.
+    // old_data: T: The element to replace.
+    // new_data: T: The element to put in its place.
+    pub fn update_element(&mut self, old_data: T, new_data: T) -> bool {
+        let position = self.list.iter().position(|item| *item == old_data);
+        match position {
+            Some(index) => self.update_element_at_index(index, new_data),
+            None => false,
+        }
+    }
+
+    // Returns true if the data is in the list, false otherwise.
+    // data: T: The element to look for.
+    pub fn find(&self, data: T) -> bool {
+        self.list.iter().any(|item| *item == data)
+    }
+}
+
+impl<T, L: ListStorage<T>> Default for UndoableList<T, L> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, L: ListStorage<T>> fmt::Debug for UndoableList<T, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.list.iter()).finish()
+    }
+}
@@ -0,0 +1,205 @@
+use std::marker::PhantomData;
+
+use crate::dynamic_list::DynamicLinkedList;
+use crate::sorted_list::InsertError;
+use crate::static_list::StaticLinkedList;
+use crate::storage::ListStorage;
+
+// An auxiliary index a UniqueList can keep next to its storage to answer
+// membership questions without scanning the list.
+pub trait MembershipIndex<T>: Default {
+    // Returns Some(true) or Some(false) if the index knows whether data is present,
+    // or None if the list has to be scanned.
+    fn contains(&self, data: &T) -> Option<bool>;
+
+    // Records that data was added to the list.
+    fn insert(&mut self, data: &T);
+
+    // Records that data was removed from the list.
+    fn remove(&mut self, data: &T);
+
+    // Forgets every element.
+    fn clear(&mut self);
+}
+
+// The default index, which keeps nothing and makes every lookup scan the list.
+#[derive(Debug, Default)]
+pub struct NoIndex;
+
+impl<T> MembershipIndex<T> for NoIndex {
+    fn contains(&self, _data: &T) -> Option<bool> {
+        None
+    }
+
+    fn insert(&mut self, _data: &T) {}
+
+    fn remove(&mut self, _data: &T) {}
+
+    fn clear(&mut self) {}
+}
+
+// A hash set holding a copy of every element, giving O(1) membership checks.
+#[cfg(feature = "hash-index")]
+#[derive(Debug)]
+pub struct HashIndex<T> {
+    set: std::collections::HashSet<T>,
+}
+
+#[cfg(feature = "hash-index")]
+impl<T> Default for HashIndex<T> {
+    fn default() -> Self {
+        HashIndex {
+            set: std::collections::HashSet::new(),
+        }
+    }
+}
+
+#[cfg(feature = "hash-index")]
+impl<T: std::hash::Hash + Eq + Clone> MembershipIndex<T> for HashIndex<T> {
+    fn contains(&self, data: &T) -> Option<bool> {
+        Some(self.set.contains(data))
+    }
+
+    fn insert(&mut self, data: &T) {
+        self.set.insert(data.clone());
+    }
+
+    fn remove(&mut self, data: &T) {
+        self.set.remove(data);
+    }
+
+    fn clear(&mut self) {
+        self.set.clear();
+    }
+}
+
+// A list that never holds two equal elements. Elements keep insertion order.
+// T: The type of data stored in the list.
+// S: The storage the elements live in, a DynamicLinkedList by default.
+// I: The membership index kept alongside the storage, none by default.
+pub struct UniqueList<
+    T: PartialEq,
+    S: ListStorage<T> = DynamicLinkedList<T>,
+    I: MembershipIndex<T> = NoIndex,
+> {
+    storage: S,
+    index: I,
+    marker: PhantomData<T>,
+}
+
+// A UniqueList stored in a fixed size StaticLinkedList.
+pub type StaticUniqueList<T, const N: usize> = UniqueList<T, StaticLinkedList<T, N>>;
+
+// A UniqueList on the heap with a hash index for O(1) membership checks.
+#[cfg(feature = "hash-index")]
+pub type HashedUniqueList<T> = UniqueList<T, DynamicLinkedList<T>, HashIndex<T>>;
+
+impl<T: PartialEq, S: ListStorage<T>, I: MembershipIndex<T>> UniqueList<T, S, I> {
+    pub fn new() -> Self {
+        UniqueList {
+            storage: S::default(),
+            index: I::default(),
+            marker: PhantomData,
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.storage.len()
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.storage.is_empty()
+    }
+
+    // Returns an iterator over the elements in insertion order.
+    pub fn iter(&self) -> S::Iter<'_> {
+        self.storage.iter()
+    }
+
+    // Returns true if an element equal to data is in the list.
+    // data: &T: The element to look for.
+    pub fn contains(&self, data: &T) -> bool {
+        match self.index.contains(data) {
+            Some(found) => found,
+            None => self.position(data).is_some(),
+        }
+    }
+
+    // Inserts data at the end of the list unless an equal element is already present.
+    // Returns true if the value was new and has been added, false otherwise.
+    // data: T: The element to insert.
+    pub fn insert(&mut self, data: T) -> bool {
+        self.try_insert(data).is_ok()
+    }
+
+    // Inserts data at the end of the list unless an equal element is already present.
+    // Returns an InsertError handing the element back if it is a duplicate or the storage is full.
+    // data: T: The element to insert.
+    pub fn try_insert(&mut self, data: T) -> Result<(), InsertError<T>> {
+        if self.contains(&data) {
+            return Err(InsertError::Duplicate(data));
+        }
+        self.push_new(data)
+    }
+
+    // Inserts data at the end of the list, or replaces the equal element already present in place.
+    // Returns Ok(Some(old)) if an element was replaced, Ok(None) if data was new,
+    // or InsertError::Full handing data back if it was new and the storage is full.
+    // data: T: The element to insert.
+    pub fn insert_or_replace(&mut self, data: T) -> Result<Option<T>, InsertError<T>> {
+        // A negative answer from the index saves the scan.
+        let position = match self.index.contains(&data) {
+            Some(false) => None,
+            _ => self.position(&data),
+        };
+
+        match position {
+            Some(i) => {
+                let slot = self.storage.get_mut(i).unwrap();
+                Ok(Some(std::mem::replace(slot, data)))
+            }
+            None => self.push_new(data).map(|()| None),
+        }
+    }
+
+    // Removes the element equal to data.
+    // Returns the removed element, or None if it was not in the list.
+    // data: &T: The element to remove.
+    pub fn remove(&mut self, data: &T) -> Option<T> {
+        if self.index.contains(data) == Some(false) {
+            return None;
+        }
+        let removed = self.storage.remove_at(self.position(data)?)?;
+        self.index.remove(&removed);
+        Some(removed)
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        self.storage.clear();
+        self.index.clear();
+    }
+
+    // Returns the index of the element equal to data by scanning the storage.
+    fn position(&self, data: &T) -> Option<usize> {
+        self.storage.iter().position(|item| item == data)
+    }
+
+    // Appends an element already known to be absent and records it in the index.
+    fn push_new(&mut self, data: T) -> Result<(), InsertError<T>> {
+        self.index.insert(&data);
+        let result = self.storage.push_back(data);
+        result.map_err(|data| {
+            self.index.remove(&data);
+            InsertError::Full(data)
+        })
+    }
+}
+
+impl<T: PartialEq, S: ListStorage<T>, I: MembershipIndex<T>> Default for UniqueList<T, S, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
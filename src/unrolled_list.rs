@@ -0,0 +1,468 @@
+use std::fmt;
+use std::ptr;
+
+// A node of an UnrolledList, holding between 1 and B elements in order.
+struct Node<T> {
+    items: Vec<T>,
+    next: Link<T>,
+}
+
+type Link<T> = Option<Box<Node<T>>>;
+
+// A linked list that stores up to B elements per node, so walking it follows one pointer per
+// B elements instead of one per element, and the elements of a node sit next to each other
+// in memory. A node that overflows on insert is split in half; a node that falls below half
+// full on delete takes elements from, or merges with, the node after it.
+// Like DynamicLinkedList it keeps a raw tail pointer so appending is O(1).
+// T: The type of data stored in the list.
+// B: The most elements a node holds, at least 2.
+pub struct UnrolledList<T, const B: usize> {
+    head: Link<T>,
+    tail: *mut Node<T>,
+    len: usize,
+}
+
+// The raw tail pointer only ever points into nodes owned by `head`,
+// so the list is as thread safe as the data it holds.
+unsafe impl<T: Send, const B: usize> Send for UnrolledList<T, B> {}
+unsafe impl<T: Sync, const B: usize> Sync for UnrolledList<T, B> {}
+
+impl<T, const B: usize> UnrolledList<T, B> {
+    pub fn new() -> Self {
+        const { assert!(B >= 2, "UnrolledList nodes must hold at least 2 elements") };
+        UnrolledList {
+            head: None,
+            tail: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter {
+            items: [].iter(),
+            next: self.head.as_deref(),
+            remaining: self.len,
+        }
+    }
+
+    // Returns an iterator over mutable references to the data, from head to tail.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        IterMut {
+            items: [].iter_mut(),
+            next: self.head.as_deref_mut(),
+            remaining: self.len,
+        }
+    }
+
+    // Returns a reference to the element at index, or None if it is out of bounds.
+    // Skips whole nodes, so it is O(n / B).
+    // index: usize: The position of the element.
+    pub fn get_ref(&self, index: usize) -> Option<&T> {
+        let mut offset = index;
+        let mut current = self.head.as_deref();
+        while let Some(node) = current {
+            if offset < node.items.len() {
+                return node.items.get(offset);
+            }
+            offset -= node.items.len();
+            current = node.next.as_deref();
+        }
+        None
+    }
+
+    // Returns a mutable reference to the element at index, or None if it is out of bounds. O(n / B).
+    // index: usize: The position of the element.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let mut offset = index;
+        let mut current = self.head.as_deref_mut();
+        while let Some(node) = current {
+            if offset < node.items.len() {
+                return node.items.get_mut(offset);
+            }
+            offset -= node.items.len();
+            current = node.next.as_deref_mut();
+        }
+        None
+    }
+
+    // Appends data at the end of the list in O(1), starting a new node when the last one is full.
+    // data: T: The element to append.
+    pub fn push_back(&mut self, data: T) {
+        // The tail pointer is valid whenever the list is non-empty.
+        if let Some(tail) = unsafe { self.tail.as_mut() }
+            && tail.items.len() < B
+        {
+            tail.items.push(data);
+        } else {
+            let slot = match unsafe { self.tail.as_mut() } {
+                Some(tail) => &mut tail.next,
+                None => &mut self.head,
+            };
+            let node = slot.insert(Box::new(Node {
+                items: Self::node_items(data),
+                next: None,
+            }));
+            self.tail = &mut **node;
+        }
+        self.len += 1;
+    }
+
+    // Inserts data at the front of the list. O(B).
+    // data: T: The element to insert.
+    pub fn push_front(&mut self, data: T) {
+        self.insert_at_index(0, data);
+    }
+
+    // Inserts a new element at the specified index in the list, splitting its node if it is full.
+    // If the index is out of bounds, the element will not be inserted. O(n / B + B).
+    // index: usize: The position the new element will occupy.
+    // data: T: The element to insert.
+    pub fn insert_at_index(&mut self, index: usize, data: T) {
+        if index > self.len {
+            return;
+        }
+        if index == self.len {
+            self.push_back(data);
+            return;
+        }
+
+        let (node, offset) = Self::locate(&mut self.head, index);
+        if node.items.len() < B {
+            node.items.insert(offset, data);
+        } else {
+            let back = node.items.split_off(B / 2);
+            let next = node.next.take();
+            let new_node = node.next.insert(Box::new(Node {
+                items: Self::with_node_capacity(back),
+                next,
+            }));
+            if new_node.next.is_none() {
+                self.tail = &mut **new_node;
+            }
+            if offset <= B / 2 {
+                node.items.insert(offset, data);
+            } else {
+                // The borrow through new_node has ended, so reach the new node again from node.
+                let new_node = node.next.as_mut().unwrap();
+                new_node.items.insert(offset - B / 2, data);
+            }
+        }
+        self.len += 1;
+    }
+
+    // Deletes the element at the specified index from the list.
+    // Returns true if the element was found and deleted, false otherwise.
+    // index: usize: The position of the element to delete.
+    pub fn delete_at_index(&mut self, index: usize) -> bool {
+        self.remove(index).is_some()
+    }
+
+    // Removes the element at the specified index and returns it, or None if it is out of bounds.
+    // A node left less than half full takes elements from the next node, or absorbs it
+    // if both fit in one node; a node left empty is unlinked. O(n / B + B).
+    // index: usize: The position of the element to remove.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+
+        let mut prev: *mut Node<T> = ptr::null_mut();
+        let mut link = &mut self.head;
+        let mut offset = index;
+        while offset >= link.as_ref().unwrap().items.len() {
+            let node = link.as_mut().unwrap();
+            offset -= node.items.len();
+            prev = &mut **node;
+            link = &mut node.next;
+        }
+
+        let node = link.as_mut().unwrap();
+        let data = node.items.remove(offset);
+        self.len -= 1;
+
+        if node.items.is_empty() {
+            let next = node.next.take();
+            *link = next;
+            if link.is_none() {
+                self.tail = prev;
+            }
+        } else if node.items.len() < B / 2
+            && let Some(next) = node.next.as_mut()
+        {
+            if node.items.len() + next.items.len() <= B {
+                let mut next = node.next.take().unwrap();
+                node.items.append(&mut next.items);
+                node.next = next.next.take();
+                if node.next.is_none() {
+                    self.tail = &mut **node;
+                }
+            } else {
+                // Both end up at least half full, as together they overfill a node.
+                let take = B / 2 - node.items.len();
+                node.items.extend(next.items.drain(..take));
+            }
+        }
+        Some(data)
+    }
+
+    // Updates the element at the specified index with new data.
+    // Returns true if the element was found and updated, false otherwise.
+    // index: usize: The position of the element to update.
+    // data: T: The new element.
+    pub fn update_element_at_index(&mut self, index: usize, data: T) -> bool {
+        match self.get_mut(index) {
+            Some(slot) => {
+                *slot = data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        *self = Self::new();
+    }
+
+    // Returns the node holding the element at index, which must be in bounds, and its offset there.
+    fn locate(head: &mut Link<T>, index: usize) -> (&mut Node<T>, usize) {
+        let mut offset = index;
+        let mut current = head.as_deref_mut().unwrap();
+        while offset >= current.items.len() {
+            offset -= current.items.len();
+            current = current.next.as_deref_mut().unwrap();
+        }
+        (current, offset)
+    }
+
+    // Returns the items of a new node holding just data, with room for B elements.
+    fn node_items(data: T) -> Vec<T> {
+        let mut items = Vec::with_capacity(B);
+        items.push(data);
+        items
+    }
+
+    // Gives items room for B elements, so a node never reallocates.
+    fn with_node_capacity(mut items: Vec<T>) -> Vec<T> {
+        items.reserve_exact(B - items.len());
+        items
+    }
+}
+
+impl<T: Clone, const B: usize> UnrolledList<T, B> {
+    // Inserts a new element at the end of the list. O(1).
+    // data: T: The element to insert.
+    pub fn insert(&mut self, data: T) {
+        self.push_back(data);
+    }
+
+    // Retrieves the data at the specified index in the list.
+    // Returns Some(data) if the index is valid, or None if it is out of bounds. O(n / B).
+    // index: usize: The index of the element to retrieve.
+    pub fn get(&self, index: usize) -> Option<T> {
+        self.get_ref(index).cloned()
+    }
+}
+
+impl<T: PartialEq, const B: usize> UnrolledList<T, B> {
+    // Deletes the first occurrence of the specified data from the list.
+    // Returns true if the element was found and deleted, false otherwise.
+    // data: T: The element to delete.
+    pub fn delete_element(&mut self, data: T) -> bool {
+        match self.iter().position(|item| *item == data) {
+            Some(index) => self.remove(index).is_some(),
+            None => false,
+        }
+    }
+
+    // Updates the first occurrence of old_data with new_data.
+    // Returns true if the element was found and updated, false otherwise.
+    // old_data: T: The element to replace.
+    // new_data: T: The element to put in its place.
+    pub fn update_element(&mut self, old_data: T, new_data: T) -> bool {
+        match self.iter_mut().find(|item| **item == old_data) {
+            Some(item) => {
+                *item = new_data;
+                true
+            }
+            None => false,
+        }
+    }
+
+    // Returns true if the data is in the list, false otherwise.
+    // data: T: The element to look for.
+    pub fn find(&self, data: T) -> bool {
+        self.iter().any(|item| *item == data)
+    }
+}
+
+impl<T, const B: usize> Default for UnrolledList<T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const B: usize> fmt::Debug for UnrolledList<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Builds a list holding the elements in iteration order, with every node but the last full.
+impl<T, const B: usize> FromIterator<T> for UnrolledList<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut list = UnrolledList::new();
+        for data in iter {
+            list.push_back(data);
+        }
+        list
+    }
+}
+
+// Frees the nodes one at a time so dropping a long list does not recurse.
+impl<T, const B: usize> Drop for UnrolledList<T, B> {
+    fn drop(&mut self) {
+        drop_chain(self.head.take());
+    }
+}
+
+fn drop_chain<T>(mut link: Link<T>) {
+    while let Some(mut node) = link {
+        link = node.next.take();
+    }
+}
+
+// Iterator over references to the data in an UnrolledList, from head to tail.
+pub struct Iter<'a, T> {
+    items: std::slice::Iter<'a, T>,
+    next: Option<&'a Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                self.remaining -= 1;
+                return Some(item);
+            }
+            let node = self.next?;
+            self.items = node.items.iter();
+            self.next = node.next.as_deref();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for Iter<'_, T> {}
+
+// Iterator over mutable references to the data in an UnrolledList, from head to tail.
+pub struct IterMut<'a, T> {
+    items: std::slice::IterMut<'a, T>,
+    next: Option<&'a mut Node<T>>,
+    remaining: usize,
+}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                self.remaining -= 1;
+                return Some(item);
+            }
+            let Node { items, next } = self.next.take()?;
+            self.items = items.iter_mut();
+            self.next = next.as_deref_mut();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IterMut<'_, T> {}
+
+// Iterator that moves the data out of an UnrolledList, from head to tail.
+pub struct IntoIter<T> {
+    items: std::vec::IntoIter<T>,
+    next: Link<T>,
+    remaining: usize,
+}
+
+impl<T> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(item) = self.items.next() {
+                self.remaining -= 1;
+                return Some(item);
+            }
+            let mut node = self.next.take()?;
+            self.next = node.next.take();
+            self.items = std::mem::take(&mut node.items).into_iter();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
+impl<T> Drop for IntoIter<T> {
+    fn drop(&mut self) {
+        drop_chain(self.next.take());
+    }
+}
+
+impl<'a, T, const B: usize> IntoIterator for &'a UnrolledList<T, B> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        self.iter()
+    }
+}
+
+impl<'a, T, const B: usize> IntoIterator for &'a mut UnrolledList<T, B> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        self.iter_mut()
+    }
+}
+
+impl<T, const B: usize> IntoIterator for UnrolledList<T, B> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(mut self) -> IntoIter<T> {
+        IntoIter {
+            items: Vec::new().into_iter(),
+            next: self.head.take(),
+            remaining: std::mem::take(&mut self.len),
+        }
+    }
+}
@@ -0,0 +1,560 @@
+// Slot links are plain index arithmetic, so this module needs no unsafe code at all.
+// Its tests also run under Miri in CI: cargo +nightly miri test --lib xor_tests
+#![forbid(unsafe_code)]
+
+use std::fmt;
+use std::ops::BitXor;
+
+use crate::storage::{DequeStorage, ListStorage};
+
+// An unsigned integer type a slot link is stored in. A narrower type than usize shrinks every
+// slot, at the cost of capping the capacity below the type's maximum value.
+// The all ones value is reserved to mean "no slot".
+pub trait LinkIndex: Copy + Eq + BitXor<Output = Self> {
+    // The link value that stands for no slot.
+    const NIL: Self;
+
+    // Returns the link value for a slot index, which must be below NIL.
+    fn from_index(index: usize) -> Self;
+
+    // Returns the slot index a link value stands for.
+    fn index(self) -> usize;
+}
+
+macro_rules! link_index {
+    ($($int:ty),*) => {
+        $(
+            impl LinkIndex for $int {
+                const NIL: Self = <$int>::MAX;
+
+                fn from_index(index: usize) -> Self {
+                    index as $int
+                }
+
+                fn index(self) -> usize {
+                    self as usize
+                }
+            }
+        )*
+    };
+}
+
+link_index!(u8, u16, u32, usize);
+
+// A slot of an XorLinkedList. An occupied slot's link is its previous slot XOR its next one,
+// with NIL standing in at the ends; a free slot's link is the next free slot.
+pub struct XorNode<T, I: LinkIndex = usize> {
+    data: Option<T>,
+    link: I,
+}
+
+// A doubly linked list in a fixed size array that keeps one link per slot, the XOR of the
+// previous and next slot indices, so it takes no more memory per slot than the singly linked
+// StaticLinkedList. Knowing either neighbour of a slot gives the other, so traversal works
+// from both ends and a cursor carries the slot behind it along with its own.
+// All link arithmetic is on slot indices, never pointers.
+// T: The type of data stored in the list.
+// N: The maximum number of nodes in the list.
+// I: The integer type links are stored in; N must be below its maximum value.
+pub struct XorLinkedList<T, const N: usize, I: LinkIndex = usize> {
+    nodes: [XorNode<T, I>; N],
+    head: Option<usize>,
+    tail: Option<usize>,
+    free: Option<usize>,
+    len: usize,
+}
+
+impl<T, const N: usize, I: LinkIndex> XorLinkedList<T, N, I> {
+    pub fn new() -> Self {
+        assert!(
+            N == 0 || (I::from_index(N - 1) != I::NIL && I::from_index(N - 1).index() == N - 1),
+            "link index type is too narrow for the capacity"
+        );
+        // Every slot starts out on the free list, chained in index order.
+        let nodes = std::array::from_fn(|i| XorNode {
+            data: None,
+            link: Self::encode((i + 1 < N).then_some(i + 1)),
+        });
+        XorLinkedList {
+            nodes,
+            head: None,
+            tail: None,
+            free: if N > 0 { Some(0) } else { None },
+            len: 0,
+        }
+    }
+
+    // Returns the number of elements in the list.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    // Returns true if the list has no elements.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Returns the maximum number of elements the list can hold.
+    pub fn capacity(&self) -> usize {
+        N
+    }
+
+    // Returns true if every slot is in use.
+    pub fn is_full(&self) -> bool {
+        self.free.is_none()
+    }
+
+    // Returns a reference to the first element, or None if the list is empty.
+    pub fn front(&self) -> Option<&T> {
+        self.head.map(|index| self.data(index))
+    }
+
+    // Returns a reference to the last element, or None if the list is empty.
+    pub fn back(&self) -> Option<&T> {
+        self.tail.map(|index| self.data(index))
+    }
+
+    // Returns a reference to the element at index, or None if it is out of bounds.
+    // Walks from whichever end is closer.
+    // index: usize: The position of the element.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        let slot = self.slot_at(index)?;
+        self.nodes[slot].data.as_ref()
+    }
+
+    // Returns a mutable reference to the element at index, or None if it is out of bounds.
+    // index: usize: The position of the element.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        let slot = self.slot_at(index)?;
+        self.nodes[slot].data.as_mut()
+    }
+
+    // Inserts data at the head in O(1).
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn push_front(&mut self, data: T) -> Result<(), T> {
+        let index = self.alloc(data)?;
+        self.link_between(None, index, self.head);
+        Ok(())
+    }
+
+    // Appends data at the tail in O(1).
+    // Returns the data back if there is no space available.
+    // data: T: The element to append.
+    pub fn push_back(&mut self, data: T) -> Result<(), T> {
+        let index = self.alloc(data)?;
+        self.link_between(self.tail, index, None);
+        Ok(())
+    }
+
+    // Removes the first element in O(1) and returns it, or None if the list is empty.
+    pub fn pop_front(&mut self) -> Option<T> {
+        let head = self.head?;
+        Some(self.unlink(None, head))
+    }
+
+    // Removes the last element in O(1) and returns it, or None if the list is empty.
+    pub fn pop_back(&mut self) -> Option<T> {
+        let tail = self.tail?;
+        let prev = self.step(None, tail);
+        Some(self.unlink(prev, tail))
+    }
+
+    // Removes every element.
+    pub fn clear(&mut self) {
+        while self.pop_front().is_some() {}
+    }
+
+    // Returns true if the data is in the list, false otherwise.
+    // data: &T: The element to look for.
+    pub fn contains(&self, data: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.iter().any(|item| item == data)
+    }
+
+    // Returns an iterator over references to the data, from head to tail.
+    // Call rev on it to go from tail to head.
+    pub fn iter(&self) -> Iter<'_, T, N, I> {
+        Iter {
+            list: self,
+            front: (None, self.head),
+            back: (None, self.tail),
+            remaining: self.len,
+        }
+    }
+
+    // Returns a cursor on the first element, or on the ghost position if the list is empty.
+    pub fn cursor_front_mut(&mut self) -> CursorMut<'_, T, N, I> {
+        CursorMut {
+            prev: None,
+            current: self.head,
+            index: 0,
+            list: self,
+        }
+    }
+
+    // Returns a cursor on the last element, or on the ghost position if the list is empty.
+    pub fn cursor_back_mut(&mut self) -> CursorMut<'_, T, N, I> {
+        let prev = self.tail.and_then(|tail| self.step(None, tail));
+        CursorMut {
+            prev,
+            current: self.tail,
+            index: self.len.saturating_sub(1),
+            list: self,
+        }
+    }
+
+    fn encode(index: Option<usize>) -> I {
+        index.map_or(I::NIL, I::from_index)
+    }
+
+    fn decode(link: I) -> Option<usize> {
+        (link != I::NIL).then(|| link.index())
+    }
+
+    // Returns the neighbour of a linked slot on the other side from from, where None is the
+    // end of the list. Walking with (previous, current) pairs works in either direction.
+    fn step(&self, from: Option<usize>, at: usize) -> Option<usize> {
+        Self::decode(self.nodes[at].link ^ Self::encode(from))
+    }
+
+    // Replaces the neighbour old of a linked slot with new.
+    fn relink(&mut self, at: usize, old: Option<usize>, new: Option<usize>) {
+        let link = &mut self.nodes[at].link;
+        *link = *link ^ Self::encode(old) ^ Self::encode(new);
+    }
+
+    fn data(&self, index: usize) -> &T {
+        self.nodes[index]
+            .data
+            .as_ref()
+            .expect("linked slot must be occupied")
+    }
+
+    // Returns the slot of the element at index, walking from the nearer end.
+    fn slot_at(&self, index: usize) -> Option<usize> {
+        if index >= self.len {
+            return None;
+        }
+        let (start, steps) = if index < self.len / 2 {
+            (self.head, index)
+        } else {
+            (self.tail, self.len - 1 - index)
+        };
+        let (mut prev, mut current) = (None, start?);
+        for _ in 0..steps {
+            let next = self.step(prev, current)?;
+            prev = Some(current);
+            current = next;
+        }
+        Some(current)
+    }
+
+    // Takes a slot off the free list and stores data in it.
+    // Returns the slot index, or gives the data back if there is no space available.
+    fn alloc(&mut self, data: T) -> Result<usize, T> {
+        let Some(index) = self.free else {
+            return Err(data);
+        };
+        self.free = Self::decode(self.nodes[index].link);
+        self.nodes[index].data = Some(data);
+        Ok(index)
+    }
+
+    // Links an allocated slot between two adjacent positions, where None is the end of the list.
+    fn link_between(&mut self, prev: Option<usize>, index: usize, next: Option<usize>) {
+        self.nodes[index].link = Self::encode(prev) ^ Self::encode(next);
+        match prev {
+            Some(prev) => self.relink(prev, next, Some(index)),
+            None => self.head = Some(index),
+        }
+        match next {
+            Some(next) => self.relink(next, prev, Some(index)),
+            None => self.tail = Some(index),
+        }
+        self.len += 1;
+    }
+
+    // Unlinks a slot given the slot before it, returns it to the free list and returns its data.
+    fn unlink(&mut self, prev: Option<usize>, index: usize) -> T {
+        let next = self.step(prev, index);
+        match prev {
+            Some(prev) => self.relink(prev, Some(index), next),
+            None => self.head = next,
+        }
+        match next {
+            Some(next) => self.relink(next, Some(index), prev),
+            None => self.tail = prev,
+        }
+        self.len -= 1;
+
+        let node = &mut self.nodes[index];
+        node.link = Self::encode(self.free);
+        self.free = Some(index);
+        node.data.take().expect("unlinked slot must be occupied")
+    }
+}
+
+impl<T, const N: usize, I: LinkIndex> Default for XorLinkedList<T, N, I> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: fmt::Debug, const N: usize, I: LinkIndex> fmt::Debug for XorLinkedList<T, N, I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+// Builds a list holding the elements in iteration order.
+// Panics if the list runs out of slots.
+impl<T, const N: usize, I: LinkIndex> FromIterator<T> for XorLinkedList<T, N, I> {
+    fn from_iter<It: IntoIterator<Item = T>>(iter: It) -> Self {
+        let mut list = XorLinkedList::new();
+        for data in iter {
+            if list.push_back(data).is_err() {
+                panic!("xor linked list is full");
+            }
+        }
+        list
+    }
+}
+
+impl<T, const N: usize, I: LinkIndex> ListStorage<T> for XorLinkedList<T, N, I> {
+    type Iter<'a>
+        = Iter<'a, T, N, I>
+    where
+        T: 'a,
+        I: 'a;
+
+    fn len(&self) -> usize {
+        self.len
+    }
+
+    fn iter(&self) -> Iter<'_, T, N, I> {
+        XorLinkedList::iter(self)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        XorLinkedList::get_mut(self, index)
+    }
+
+    fn push_back(&mut self, data: T) -> Result<(), T> {
+        XorLinkedList::push_back(self, data)
+    }
+
+    fn insert_at(&mut self, index: usize, data: T) -> Result<(), T> {
+        if index > self.len {
+            return Err(data);
+        }
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.insert_before(data)
+    }
+
+    fn push_front(&mut self, data: T) -> Result<(), T> {
+        XorLinkedList::push_front(self, data)
+    }
+
+    fn pop_front(&mut self) -> Option<T> {
+        XorLinkedList::pop_front(self)
+    }
+
+    fn remove_at(&mut self, index: usize) -> Option<T> {
+        if index >= self.len {
+            return None;
+        }
+        let mut cursor = self.cursor_front_mut();
+        for _ in 0..index {
+            cursor.move_next();
+        }
+        cursor.remove_current()
+    }
+
+    fn clear(&mut self) {
+        XorLinkedList::clear(self);
+    }
+}
+
+impl<T, const N: usize, I: LinkIndex> DequeStorage<T> for XorLinkedList<T, N, I> {
+    fn back(&self) -> Option<&T> {
+        XorLinkedList::back(self)
+    }
+
+    fn pop_back(&mut self) -> Option<T> {
+        XorLinkedList::pop_back(self)
+    }
+}
+
+// A walk position: the slot just left and the slot to yield next, None past either end.
+type Position = (Option<usize>, Option<usize>);
+
+// Iterator over references to the data in an XorLinkedList, from either end.
+pub struct Iter<'a, T, const N: usize, I: LinkIndex = usize> {
+    list: &'a XorLinkedList<T, N, I>,
+    front: Position,
+    back: Position,
+    remaining: usize,
+}
+
+impl<'a, T, const N: usize, I: LinkIndex> Iter<'a, T, N, I> {
+    // Returns the data at a position and the position after it, counting it off remaining.
+    fn advance(&mut self, (from, at): Position) -> Option<(&'a T, Position)> {
+        if self.remaining == 0 {
+            return None;
+        }
+        let at = at?;
+        self.remaining -= 1;
+        Some((self.list.data(at), (Some(at), self.list.step(from, at))))
+    }
+}
+
+impl<'a, T, const N: usize, I: LinkIndex> Iterator for Iter<'a, T, N, I> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (data, front) = self.advance(self.front)?;
+        self.front = front;
+        Some(data)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<'a, T, const N: usize, I: LinkIndex> DoubleEndedIterator for Iter<'a, T, N, I> {
+    fn next_back(&mut self) -> Option<&'a T> {
+        let (data, back) = self.advance(self.back)?;
+        self.back = back;
+        Some(data)
+    }
+}
+
+impl<T, const N: usize, I: LinkIndex> ExactSizeIterator for Iter<'_, T, N, I> {}
+
+impl<'a, T, const N: usize, I: LinkIndex> IntoIterator for &'a XorLinkedList<T, N, I> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T, N, I>;
+
+    fn into_iter(self) -> Iter<'a, T, N, I> {
+        self.iter()
+    }
+}
+
+// A position in an XorLinkedList that can move both ways and insert or remove there in O(1).
+// Besides the elements it can rest on a ghost position between the tail and the head,
+// as the cursors on std's LinkedList do. It holds the slot before the current one,
+// which is what the XOR links need to find the slot after it.
+pub struct CursorMut<'a, T, const N: usize, I: LinkIndex = usize> {
+    list: &'a mut XorLinkedList<T, N, I>,
+    // The slot before the current one; None at the head and on the ghost position.
+    prev: Option<usize>,
+    // The current slot, or None on the ghost position.
+    current: Option<usize>,
+    index: usize,
+}
+
+impl<T, const N: usize, I: LinkIndex> CursorMut<'_, T, N, I> {
+    // Returns the position of the current element, or None on the ghost position.
+    pub fn index(&self) -> Option<usize> {
+        self.current.map(|_| self.index)
+    }
+
+    // Returns a reference to the current element, or None on the ghost position.
+    pub fn current(&self) -> Option<&T> {
+        self.current.map(|index| self.list.data(index))
+    }
+
+    // Returns a mutable reference to the current element, or None on the ghost position.
+    pub fn current_mut(&mut self) -> Option<&mut T> {
+        self.list.nodes[self.current?].data.as_mut()
+    }
+
+    // Moves to the next element. From the tail it moves to the ghost position,
+    // and from the ghost position to the head.
+    pub fn move_next(&mut self) {
+        match self.current {
+            Some(current) => {
+                let next = self.list.step(self.prev, current);
+                self.prev = next.map(|_| current);
+                self.current = next;
+                self.index += 1;
+            }
+            None => {
+                self.current = self.list.head;
+                self.index = 0;
+            }
+        }
+    }
+
+    // Moves to the previous element. From the head it moves to the ghost position,
+    // and from the ghost position to the tail.
+    pub fn move_prev(&mut self) {
+        match (self.current, self.prev) {
+            (Some(current), Some(prev)) => {
+                self.prev = self.list.step(Some(current), prev);
+                self.current = Some(prev);
+                self.index -= 1;
+            }
+            (Some(_), None) => self.current = None,
+            (None, _) => {
+                self.current = self.list.tail;
+                self.prev = self.current.and_then(|tail| self.list.step(None, tail));
+                self.index = self.list.len.saturating_sub(1);
+            }
+        }
+    }
+
+    // Inserts data after the current element; on the ghost position that is at the head.
+    // The cursor stays where it is.
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn insert_after(&mut self, data: T) -> Result<(), T> {
+        let index = self.list.alloc(data)?;
+        match self.current {
+            Some(current) => {
+                let next = self.list.step(self.prev, current);
+                self.list.link_between(Some(current), index, next);
+            }
+            None => self.list.link_between(None, index, self.list.head),
+        }
+        Ok(())
+    }
+
+    // Inserts data before the current element; on the ghost position that is at the tail.
+    // The cursor stays on the same element, whose index goes up by one.
+    // Returns the data back if there is no space available.
+    // data: T: The element to insert.
+    pub fn insert_before(&mut self, data: T) -> Result<(), T> {
+        let index = self.list.alloc(data)?;
+        match self.current {
+            Some(current) => {
+                self.list.link_between(self.prev, index, Some(current));
+                self.prev = Some(index);
+                self.index += 1;
+            }
+            None => self.list.link_between(self.list.tail, index, None),
+        }
+        Ok(())
+    }
+
+    // Removes the current element and returns it; the cursor moves on to the next element,
+    // or to the ghost position if it removed the tail.
+    // Returns None on the ghost position.
+    pub fn remove_current(&mut self) -> Option<T> {
+        let current = self.current?;
+        let next = self.list.step(self.prev, current);
+        let data = self.list.unlink(self.prev, current);
+        if next.is_none() {
+            self.prev = None;
+        }
+        self.current = next;
+        Some(data)
+    }
+}
@@ -0,0 +1,74 @@
+// Runs every scenario in tests/scripts through the list binary and checks its exit code.
+// A scenario passes when every expect and validate line in it holds.
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+fn run_binary(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_payton_Assignment_2"))
+        .args(args)
+        .output()
+        .expect("failed to run the list binary")
+}
+
+fn scripts() -> Vec<PathBuf> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/scripts");
+    let mut scripts: Vec<PathBuf> = std::fs::read_dir(&dir)
+        .expect("tests/scripts is missing")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|extension| extension == "txt"))
+        .collect();
+    scripts.sort();
+    scripts
+}
+
+#[test]
+fn test_scripts_pass() {
+    let scripts = scripts();
+    assert!(!scripts.is_empty(), "no scripts found in tests/scripts");
+
+    for script in scripts {
+        let output = run_binary(&["--script", script.to_str().unwrap(), "--trace"]);
+        assert!(
+            output.status.success(),
+            "{} failed with {}\n{}{}",
+            script.display(),
+            output.status,
+            String::from_utf8_lossy(&output.stdout),
+            String::from_utf8_lossy(&output.stderr)
+        );
+    }
+}
+
+#[test]
+fn test_exit_codes() {
+    let dir = std::env::temp_dir().join(format!("list-scripts-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+
+    let failing = dir.join("failing.txt");
+    std::fs::write(&failing, "insert 1\nexpect get 0 == 2\nexpect len == 1\n").unwrap();
+    let output = run_binary(&["--script", failing.to_str().unwrap()]);
+    assert_eq!(output.status.code(), Some(1));
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("FAILED at line 2: expected get 0 == 2, found 1"));
+    assert!(stdout.contains("1 failed check(s)"));
+
+    let malformed = dir.join("malformed.txt");
+    std::fs::write(&malformed, "insert one\n").unwrap();
+    assert_eq!(
+        run_binary(&["--script", malformed.to_str().unwrap()])
+            .status
+            .code(),
+        Some(2)
+    );
+
+    let missing = dir.join("missing.txt");
+    assert_eq!(
+        run_binary(&["--script", missing.to_str().unwrap()])
+            .status
+            .code(),
+        Some(2)
+    );
+
+    std::fs::remove_dir_all(&dir).unwrap();
+}